@@ -1,8 +1,8 @@
 use crate::backend::os::process::{Process, ProcessInfo};
-use crate::backend::state::AppState;
+use crate::backend::state::{AppState, PARSE_STATE_IDLE, PARSE_STATE_PAUSED, PARSE_STATE_RUNNING};
 use crate::backend::unreal::dumper::BaseAddressDumper;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{Emitter, State};
 
 #[tauri::command]
 pub fn fetch_system_processes() -> Vec<ProcessInfo> {
@@ -12,7 +12,69 @@ pub fn fetch_system_processes() -> Vec<ProcessInfo> {
 #[tauri::command]
 pub fn attach_to_process(state: State<'_, AppState>, pid: u32, name: String) -> Result<String, String> {
     println!("Attaching to process {} ({})", name, pid);
-    Process::attach(&state, pid, &name)
+    let attach_result = Process::attach(&state, pid, &name)?;
+
+    // Auto-select an offset profile matching the detected engine version, so the caller doesn't
+    // have to remember to call `set_offset_profile` before parsing a different UE build. Only
+    // switches if a profile under that exact version name has already been saved.
+    if let Some(version) = state.process.lock().unwrap().as_ref().and_then(|p| p.get_ue_version().ok()) {
+        if let Some(profile_name) = state.offset_profiles.lock().unwrap().resolve_for_version(&version) {
+            println!("[attach_to_process] Detected engine version {}, switching to offset profile '{}'", version, profile_name);
+            *state.active_profile_name.lock().unwrap() = profile_name;
+            state.member_index.invalidate();
+        }
+    }
+
+    // If a previous session already saved a dump for this exact process/build/profile, hydrate
+    // the object cache from it instead of making the caller re-walk GUObjectArray/FNamePool.
+    let dump_path = crate::backend::unreal::dump_store::default_dump_path(&name);
+    if let Ok(meta) = crate::backend::unreal::dump_store::read_meta(&dump_path) {
+        let module_base = state.process.lock().unwrap().as_ref().map(|p| p.main_module_base).unwrap_or(0);
+        let active_profile = state.active_profile_name.lock().unwrap().clone();
+
+        if meta.process_name == name && meta.module_base == module_base && meta.offset_profile == active_profile {
+            match crate::backend::unreal::dump_store::load_dump(&dump_path, &state.object_manager) {
+                Ok(count) => {
+                    println!("[attach_to_process] Hydrated {} object(s) from {:?}", count, dump_path);
+                    return Ok(format!("{} (hydrated {} objects from a saved dump)", attach_result, count));
+                }
+                Err(e) => println!("[attach_to_process] Failed to hydrate dump at {:?}: {}", dump_path, e),
+            }
+        } else {
+            println!("[attach_to_process] Found a dump at {:?} but it's stale (process/module/profile mismatch), ignoring", dump_path);
+        }
+    }
+
+    Ok(attach_result)
+}
+
+/// Serializes the parsed object cache to disk, tagged with the attached process's name, main
+/// module base, and active offset profile, so a later `attach_to_process` to the same build can
+/// skip re-walking GUObjectArray and FNamePool entirely.
+#[tauri::command]
+pub fn save_dump(state: State<'_, AppState>) -> Result<String, String> {
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let meta = crate::backend::unreal::dump_store::DumpMeta {
+        process_name: proc.name.clone(),
+        module_base: proc.main_module_base,
+        offset_profile: state.active_profile_name.lock().unwrap().clone(),
+    };
+
+    let path = crate::backend::unreal::dump_store::default_dump_path(&proc.name);
+    let count = crate::backend::unreal::dump_store::save_dump(&path, &state.object_manager, &meta)?;
+    Ok(format!("Saved {} object(s) to {:?}", count, path))
+}
+
+/// Loads a previously saved dump for `process_name` regardless of whether a live process is
+/// attached, so `get_packages`/`get_objects`/`get_object_details` become usable for static
+/// inspection without re-running the live memory scan.
+#[tauri::command]
+pub fn load_dump(state: State<'_, AppState>, process_name: String) -> Result<String, String> {
+    let path = crate::backend::unreal::dump_store::default_dump_path(&process_name);
+    let count = crate::backend::unreal::dump_store::load_dump(&path, &state.object_manager)?;
+    Ok(format!("Loaded {} object(s) from {:?}", count, path))
 }
 
 #[tauri::command]
@@ -100,21 +162,49 @@ pub fn get_ue_version(state: State<'_, AppState>) -> Result<String, String> {
     }
 }
 
+/// Surfaces the attached process's command line, working directory, parent PID, start time, and
+/// environment — reads straight out of its PEB via `Process::get_details`, for the attach panel
+/// to render as a full process inspector.
+#[tauri::command]
+pub fn get_process_details(state: State<'_, AppState>) -> Result<crate::backend::os::process::ProcessDetails, String> {
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+    process.get_details()
+}
+
+#[derive(serde::Serialize)]
+pub struct ParsePoolResult {
+    pub valid_blocks: u32,
+    pub valid_names: u32,
+    /// Equals the total batch count when the run finished normally, otherwise the batch to pass
+    /// back as `start_batch` on the next call to resume a paused/cancelled run.
+    pub next_batch: usize,
+}
+
+/// `start_batch` resumes a previously paused/cancelled run; omit (or pass 0) to start fresh.
 #[tauri::command]
-pub async fn parse_fname_pool(app_handle: tauri::AppHandle, state: State<'_, AppState>) -> Result<u32, String> {
+pub async fn parse_fname_pool(app_handle: tauri::AppHandle, state: State<'_, AppState>, start_batch: Option<usize>) -> Result<ParsePoolResult, String> {
+    let start_batch = start_batch.unwrap_or(0);
+
     // Safely extract owned data from state before handing off to spawn_blocking
     let process = state.process.lock().unwrap().clone().ok_or_else(|| "No process attached".to_string())?;
     let base_address = BaseAddressDumper::get_fname_pool(&process)?;
+    let offsets = state.active_offsets();
 
-    tauri::async_runtime::spawn_blocking(move || {
-        let pool = crate::backend::unreal::name_pool::FNamePool::new(base_address);
-        match pool.parse_pool(&process, &app_handle) {
-            Ok((valid_blocks, valid_names)) => {
+    state.parse_cancel.store(false, std::sync::atomic::Ordering::Relaxed);
+    state.parse_run_state.store(PARSE_STATE_RUNNING, std::sync::atomic::Ordering::Relaxed);
+    let cancel_flag = Arc::clone(&state.parse_cancel);
+    let run_state = Arc::clone(&state.parse_run_state);
+
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let pool = crate::backend::unreal::name_pool::FNamePool::new(base_address, &offsets);
+        match pool.parse_pool(&process, &app_handle, &cancel_flag, &run_state, start_batch) {
+            Ok((valid_blocks, valid_names, next_batch)) => {
                 println!("\n====== FNamePool Parsing ======");
                 println!("[ FNamePool Quantity ] {}", valid_blocks);
                 println!("[ FNamePool Valid Names ] {}", valid_names);
                 println!("===============================\n");
-                Ok(valid_blocks)
+                Ok(ParsePoolResult { valid_blocks, valid_names, next_batch })
             }
             Err(e) => {
                 println!("Failed to parse FNamePool: {}", e);
@@ -123,36 +213,66 @@ pub async fn parse_fname_pool(app_handle: tauri::AppHandle, state: State<'_, App
         }
     })
     .await
-    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+
+    state.parse_run_state.store(PARSE_STATE_IDLE, std::sync::atomic::Ordering::Relaxed);
+    result
+}
+
+#[derive(serde::Serialize)]
+pub struct ParseObjectArrayResult {
+    pub object_count: u32,
+    /// Region index to pass back as `start_batch` on the next call to resume; equals the total
+    /// region count when the run finished normally.
+    pub next_batch: usize,
 }
 
+/// `start_batch` resumes a previously paused/cancelled run from a given region index instead of
+/// always restarting at the beginning; omit (or pass 0) to start fresh. A fresh run clears the
+/// object cache, a resumed one keeps the partial cache from the run it's continuing.
 #[tauri::command]
-pub async fn parse_guobject_array(app_handle: tauri::AppHandle, state: State<'_, AppState>) -> Result<u32, String> {
+pub async fn parse_guobject_array(app_handle: tauri::AppHandle, state: State<'_, AppState>, start_batch: Option<usize>) -> Result<ParseObjectArrayResult, String> {
+    let start_batch = start_batch.unwrap_or(0);
+
     // Safely extract owned/Arc data from state before handing off to spawn_blocking
     let process = state.process.lock().unwrap().clone().ok_or_else(|| "No process attached".to_string())?;
     let fname_pool_addr = BaseAddressDumper::get_fname_pool(&process)?;
     let (guobject_addr, element_size) = BaseAddressDumper::get_guobject_array_with_element_size(&process)?;
 
-    let name_pool = Arc::new(crate::backend::unreal::name_pool::FNamePool::new(fname_pool_addr));
-    {
+    let offsets = state.active_offsets();
+
+    let name_pool = if start_batch == 0 {
+        let fresh = Arc::new(crate::backend::unreal::name_pool::FNamePool::new(fname_pool_addr, &offsets));
         let mut np_lock = state.name_pool.lock().unwrap();
-        *np_lock = Some(Arc::clone(&name_pool));
-    }
+        *np_lock = Some(Arc::clone(&fresh));
+        fresh
+    } else {
+        state.name_pool.lock().unwrap().as_ref().ok_or("No paused FNamePool to resume from")?.clone()
+    };
 
     let obj_mgr = Arc::clone(&state.object_manager);
-    obj_mgr.cache_by_address.clear();
-    obj_mgr.cache_by_id.clear();
-    obj_mgr.total_object_count.store(0, std::sync::atomic::Ordering::Relaxed);
+    if start_batch == 0 {
+        obj_mgr.cache_by_address.clear();
+        obj_mgr.cache_by_id.clear();
+        obj_mgr.total_object_count.store(0, std::sync::atomic::Ordering::Relaxed);
+        // Class addresses are about to be rebuilt from scratch, so any previously indexed
+        // (class_address, member) pairs are stale.
+        state.member_index.invalidate();
+    }
 
-    tauri::async_runtime::spawn_blocking(move || {
+    state.parse_cancel.store(false, std::sync::atomic::Ordering::Relaxed);
+    state.parse_run_state.store(PARSE_STATE_RUNNING, std::sync::atomic::Ordering::Relaxed);
+    let cancel_flag = Arc::clone(&state.parse_cancel);
+    let run_state = Arc::clone(&state.parse_run_state);
+
+    let result = tauri::async_runtime::spawn_blocking(move || {
         let obj_array = crate::backend::unreal::object_array::GUObjectArray::new(guobject_addr);
-        let offsets = crate::backend::unreal::offsets::UEOffset::default();
-        match obj_array.parse_array(&process, &name_pool, &offsets, element_size, &app_handle, &obj_mgr) {
-            Ok(count) => {
+        match obj_array.parse_array(&process, &name_pool, &offsets, element_size, &app_handle, &obj_mgr, &cancel_flag, &run_state, start_batch) {
+            Ok((count, next_batch)) => {
                 println!("\n====== GUObjectArray Parsing ======");
                 println!("[ GUObjectArray Total Objects ] {}", count);
                 println!("===================================\n");
-                Ok(count)
+                Ok(ParseObjectArrayResult { object_count: count, next_batch })
             }
             Err(e) => {
                 println!("Failed to parse GUObjectArray: {}", e);
@@ -161,7 +281,30 @@ pub async fn parse_guobject_array(app_handle: tauri::AppHandle, state: State<'_,
         }
     })
     .await
-    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+
+    state.parse_run_state.store(PARSE_STATE_IDLE, std::sync::atomic::Ordering::Relaxed);
+    result
+}
+
+/// Requests cancellation of an in-flight `parse_fname_pool`/`parse_guobject_array` job; the
+/// parser checks this between batches and stops early with a partial, resumable result instead
+/// of running to completion.
+#[tauri::command]
+pub fn cancel_parse(state: State<'_, AppState>) {
+    state.parse_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Pauses an in-flight parse: its batch loop blocks in place (no work lost) instead of skipping
+/// ahead, so `resume_parse` continues from exactly where it stopped.
+#[tauri::command]
+pub fn pause_parse(state: State<'_, AppState>) {
+    state.parse_run_state.store(PARSE_STATE_PAUSED, std::sync::atomic::Ordering::Relaxed);
+}
+
+#[tauri::command]
+pub fn resume_parse(state: State<'_, AppState>) {
+    state.parse_run_state.store(PARSE_STATE_RUNNING, std::sync::atomic::Ordering::Relaxed);
 }
 
 #[derive(serde::Serialize)]
@@ -199,8 +342,20 @@ fn extract_package_name(input: &str) -> String {
     input[first_slash..].to_string()
 }
 
+/// A windowed slice of a larger result set, paired with the *unwindowed* total so a caller can
+/// tell whether it's seen everything or needs to page further (e.g. render "1-50 of 4,213").
+#[derive(serde::Serialize)]
+pub struct PagedPackages {
+    pub packages: Vec<PackageInfo>,
+    pub total_count: usize,
+}
+
+/// Same windowing as `get_objects`, for the package listing. The full `cache_by_address` scan
+/// still has to happen either way (the package/count aggregation can't be done without visiting
+/// every object) — `offset`/`limit` only bound what's handed back afterward, so a project with
+/// thousands of packages doesn't serialize them all in one response.
 #[tauri::command]
-pub fn get_packages(state: State<'_, AppState>) -> Result<Vec<PackageInfo>, String> {
+pub fn get_packages(state: State<'_, AppState>, offset: usize, limit: usize) -> Result<PagedPackages, String> {
     let obj_mgr = &state.object_manager;
     let mut package_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
 
@@ -215,9 +370,12 @@ pub fn get_packages(state: State<'_, AppState>) -> Result<Vec<PackageInfo>, Stri
     }
 
     let mut packages: Vec<PackageInfo> = package_counts.into_iter().map(|(name, count)| PackageInfo { name, object_count: count }).collect();
-
     packages.sort_by(|a, b| a.name.cmp(&b.name));
-    Ok(packages)
+
+    let total_count = packages.len();
+    let page = if offset >= total_count { Vec::new() } else { packages.drain(offset..).take(limit).collect() };
+
+    Ok(PagedPackages { packages: page, total_count })
 }
 
 #[derive(serde::Serialize)]
@@ -228,8 +386,14 @@ pub struct ObjectSummary {
     pub type_name: String,
 }
 
+#[derive(serde::Serialize)]
+pub struct PagedObjects {
+    pub objects: Vec<ObjectSummary>,
+    pub total_count: usize,
+}
+
 #[tauri::command]
-pub fn get_objects(state: State<'_, AppState>, package_name: String, category: String) -> Result<Vec<ObjectSummary>, String> {
+pub fn get_objects(state: State<'_, AppState>, package_name: String, category: String, offset: usize, limit: usize) -> Result<PagedObjects, String> {
     let obj_mgr = &state.object_manager;
     let mut results = Vec::new();
 
@@ -252,7 +416,11 @@ pub fn get_objects(state: State<'_, AppState>, package_name: String, category: S
     }
 
     results.sort_by(|a, b| a.name.cmp(&b.name));
-    Ok(results)
+
+    let total_count = results.len();
+    let page = if offset >= total_count { Vec::new() } else { results.drain(offset..).take(limit).collect() };
+
+    Ok(PagedObjects { objects: page, total_count })
 }
 
 #[derive(serde::Serialize, Clone)]
@@ -287,7 +455,7 @@ pub struct FunctionParamInfo {
 pub struct DetailedObjectInfo {
     pub address: usize,
     pub function_address: usize, // only for Function type
-    pub function_offset: String, // hex offset for function
+    pub function_offset: String, // "ModuleName.dll+0x1234" via AppState::resolve_rva, or plain hex if no owning module was found
     pub name: String,
     pub full_name: String,
     pub type_name: String,
@@ -316,7 +484,7 @@ pub fn get_object_details(state: State<'_, AppState>, address: usize) -> Result<
         let np_lock = state.name_pool.lock().unwrap();
         np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
     };
-    let offsets = crate::backend::unreal::offsets::UEOffset::default();
+    let offsets = state.active_offsets();
 
     println!("[get_object_details] Starting for '{}' type='{}' addr=0x{:X}", obj.name, obj.type_name, address);
 
@@ -358,6 +526,10 @@ pub fn get_object_details(state: State<'_, AppState>, address: usize) -> Result<
         // Read PropSize
         result.prop_size = process.memory.try_read::<i32>(address.wrapping_add(offsets.prop_size)).unwrap_or(0);
 
+        // Sub-type resolution program for this walk — defaults to the built-in fallback chain,
+        // but can be swapped per-build via `set_resolve_steps`.
+        let resolve_steps = state.active_resolve_steps();
+
         // ═══ Walk children (ChildProperty chain) ═══
         let mut child_addr = process.memory.try_read_pointer(address.wrapping_add(offsets.member)).unwrap_or(0);
         println!("[get_object_details] '{}' type='{}' addr=0x{:X} member_offset=0x{:X} first_child=0x{:X}", obj.name, obj.type_name, address, offsets.member, child_addr);
@@ -380,14 +552,13 @@ pub fn get_object_details(state: State<'_, AppState>, address: usize) -> Result<
 
             println!("[get_object_details]   child[{}] addr=0x{:X} name='{}' type='{}' offset=0x{:X}", safety - 1, child_addr, child_name, child_type, child_offset);
 
-            // Read sub-type for complex properties
-            // Matches old C++ GetProperty: try Property_8 → Property_0 → TypeObject fallback
+            // Read sub-type for complex properties, driven by whatever `ResolveStep` program is
+            // active for this build (defaults to the Property_8 → Property_0 → TypeObject
+            // fallback documented on `default_sub_type_steps`).
             let mut sub_type = String::new();
             let mut sub_type_address: usize = 0;
 
-            let prop_0 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property)).unwrap_or(0);
-            let prop_8 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property + 8)).unwrap_or(0);
-            let type_obj = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.type_object)).unwrap_or(0);
+            let resolve_ctx = crate::backend::unreal::resolve_step::ResolveContext { process, obj_mgr: obj_mgr.as_ref(), name_pool: name_pool.as_ref(), offsets: &offsets };
 
             if child_type.contains("StructProperty")
                 || child_type.contains("ObjectProperty")
@@ -400,40 +571,25 @@ pub fn get_object_details(state: State<'_, AppState>, address: usize) -> Result<
                 || child_type.contains("SetProperty")
                 || child_type.contains("InterfaceProperty")
             {
-                // Fallback: Property_8 → Property_0 → TypeObject (matching old C++)
-                let candidates = [prop_8, prop_0, type_obj];
-                for &addr in &candidates {
-                    if addr > 0x10000 {
-                        if let Some(sub_obj) = obj_mgr.cache_by_address.get(&addr) {
-                            sub_type = sub_obj.name.clone();
-                            sub_type_address = sub_obj.address;
-                            break;
-                        } else {
-                            // Try reading FName directly from the object
-                            let sub_name_id = process.memory.try_read::<i32>(addr.wrapping_add(offsets.fname_index)).unwrap_or(0);
-                            if let Ok(name) = name_pool.get_name(process, sub_name_id as u32) {
-                                if !name.is_empty() {
-                                    sub_type = name;
-                                    sub_type_address = addr;
-                                    break;
-                                }
-                            }
-                        }
-                    }
+                if let Some((name, addr)) = crate::backend::unreal::resolve_step::resolve(&resolve_steps, child_addr, &resolve_ctx) {
+                    sub_type = name;
+                    sub_type_address = addr;
                 }
             } else if child_type.contains("MapProperty") {
-                // MapProperty: read both key and value sub-types
+                // MapProperty: read both key and value sub-types — Property_0 for the key,
+                // Property_8 for the value — each resolved the same way a single candidate in
+                // `resolve_steps` would be (prefer the cached object's name, else its raw FName).
+                let terminal = [crate::backend::unreal::resolve_step::ResolveStep::FirstOf(vec![
+                    crate::backend::unreal::resolve_step::ResolveStep::TryCache,
+                    crate::backend::unreal::resolve_step::ResolveStep::ResolveName,
+                ])];
+                let prop_0 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property)).unwrap_or(0);
+                let prop_8 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property + 8)).unwrap_or(0);
                 let mut parts = vec![];
-                // Try Property_0 for first sub-type, Property_8 for second
                 for &addr in &[prop_0, prop_8] {
                     if addr > 0x10000 {
-                        if let Some(sub_obj) = obj_mgr.cache_by_address.get(&addr) {
-                            parts.push(sub_obj.name.clone());
-                        } else {
-                            let sub_name_id = process.memory.try_read::<i32>(addr.wrapping_add(offsets.fname_index)).unwrap_or(0);
-                            if let Ok(name) = name_pool.get_name(process, sub_name_id as u32) {
-                                parts.push(name);
-                            }
+                        if let Some((name, _)) = crate::backend::unreal::resolve_step::resolve(&terminal, addr, &resolve_ctx) {
+                            parts.push(name);
                         }
                     }
                 }
@@ -490,8 +646,11 @@ pub fn get_object_details(state: State<'_, AppState>, address: usize) -> Result<
         // ═══ Function: read address, owner, params ═══
         result.function_address = process.memory.try_read_pointer(address.wrapping_add(offsets.funct)).unwrap_or(0);
         if result.function_address > 0 {
-            // Calculate offset relative to module base (rough estimate)
-            result.function_offset = format!("0x{:X}", result.function_address);
+            result.function_offset = match state.resolve_rva(result.function_address) {
+                Some((module, rva)) => format!("{}+0x{:X}", module, rva),
+                None => format!("0x{:X}", result.function_address),
+            };
+            println!("[get_object_details] Function '{}' at 0x{:X} [{}]", obj.name, result.function_address, result.function_offset);
         }
 
         // Function owner (Outer)
@@ -548,57 +707,162 @@ pub struct GlobalSearchResult {
     pub member_name: Option<String>,
 }
 
+/// Dispatches `global_search`'s text matching to either a plain substring check or a
+/// precompiled regex, mirroring the small-enum-plus-dispatch pattern `PropertyValue` already
+/// uses for property decoding.
+enum QueryMatcher {
+    Substring(String),
+    Regex(regex::Regex),
+}
+
+impl QueryMatcher {
+    fn new(query: &str, search_mode: &str) -> Result<Self, String> {
+        if search_mode == "Regex" {
+            regex::Regex::new(query).map(QueryMatcher::Regex).map_err(|e| format!("Invalid regex '{}': {}", query, e))
+        } else {
+            Ok(QueryMatcher::Substring(query.to_lowercase()))
+        }
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        match self {
+            QueryMatcher::Substring(q) => text.to_lowercase().contains(q.as_str()),
+            QueryMatcher::Regex(re) => re.is_match(text),
+        }
+    }
+}
+
+/// Matches `get_objects`' category classification so `global_search`'s `category_filter`
+/// behaves the same way as the package/category browser.
+fn category_matches(type_name: &str, category_filter: &str) -> bool {
+    match category_filter {
+        "Class" => type_name.contains("Class") && !type_name.contains("Function"),
+        "Struct" => type_name.contains("Struct") && !type_name.contains("Function"),
+        "Enum" => type_name.contains("Enum"),
+        "Function" => type_name.contains("Function"),
+        _ => true, // unrecognized/empty filter: don't scope
+    }
+}
+
 #[tauri::command]
-pub async fn global_search(state: State<'_, AppState>, query: String, search_mode: String) -> Result<Vec<GlobalSearchResult>, String> {
+pub async fn global_search(
+    state: State<'_, AppState>,
+    query: String,
+    search_mode: String,
+    package_filter: Option<String>,
+    category_filter: Option<String>,
+    max_results: Option<usize>,
+) -> Result<Vec<GlobalSearchResult>, String> {
     let query_lower = query.to_lowercase();
     let obj_mgr = Arc::clone(&state.object_manager);
+    let member_index = Arc::clone(&state.member_index);
+    let matcher = QueryMatcher::new(&query, &search_mode)?;
+    let limit = max_results.unwrap_or(500);
 
-    // For Member search, we need process & name_pool
+    // For Member search without a built index, we fall back to the live process & name_pool.
     let process = state.process.lock().unwrap().clone();
     let name_pool = {
         let np_lock = state.name_pool.lock().unwrap();
         np_lock.clone()
     };
+    let offsets = state.active_offsets();
 
     tauri::async_runtime::spawn_blocking(move || {
         let mut results = Vec::new();
-        let limit = 500; // Limit results for performance
-        let offsets = crate::backend::unreal::offsets::UEOffset::default();
 
-        for entry in obj_mgr.cache_by_address.iter() {
-            if results.len() >= limit {
-                break;
+        let scope_ok = |obj_type: &str, pkg_name: &str| -> bool {
+            if let Some(cat) = &category_filter {
+                if !category_matches(obj_type, cat) {
+                    return false;
+                }
             }
-            let obj = entry.value();
-
-            let pkg_name = extract_package_name(&obj.full_name);
+            if let Some(pkg) = &package_filter {
+                if pkg_name != pkg {
+                    return false;
+                }
+            }
+            true
+        };
+
+        if search_mode == "Member" && member_index.is_built() {
+            // Cheap lookup against the one-time index instead of an O(objects × members) live scan.
+            for (class_address, member_name) in member_index.search(&query_lower) {
+                if results.len() >= limit {
+                    break;
+                }
+                if let Some(obj) = obj_mgr.cache_by_address.get(&class_address) {
+                    let pkg_name = extract_package_name(&obj.full_name);
+                    if !scope_ok(&obj.type_name, &pkg_name) {
+                        continue;
+                    }
+                    results.push(GlobalSearchResult { package_name: pkg_name, object_name: obj.name.clone(), type_name: obj.type_name.clone(), address: obj.address, member_name: Some(member_name) });
+                }
+            }
+        } else {
+            for entry in obj_mgr.cache_by_address.iter() {
+                if results.len() >= limit {
+                    break;
+                }
+                let obj = entry.value();
+                let pkg_name = extract_package_name(&obj.full_name);
+                if !scope_ok(&obj.type_name, &pkg_name) {
+                    continue;
+                }
 
-            if search_mode == "Object" {
-                let t_lower = obj.type_name.to_lowercase();
-                let is_valid_type = t_lower.contains("class") || t_lower.contains("struct") || t_lower.contains("enum") || t_lower == "userenum" || t_lower.contains("function");
+                if search_mode == "Object" || search_mode == "Regex" {
+                    let t_lower = obj.type_name.to_lowercase();
+                    let is_valid_type = t_lower.contains("class") || t_lower.contains("struct") || t_lower.contains("enum") || t_lower == "userenum" || t_lower.contains("function");
 
-                if is_valid_type && obj.name.to_lowercase().contains(&query_lower) {
-                    results.push(GlobalSearchResult { package_name: pkg_name, object_name: obj.name.clone(), type_name: obj.type_name.clone(), address: obj.address, member_name: None });
+                    if is_valid_type && (matcher.is_match(&obj.name) || matcher.is_match(&obj.full_name)) {
+                        results.push(GlobalSearchResult { package_name: pkg_name.clone(), object_name: obj.name.clone(), type_name: obj.type_name.clone(), address: obj.address, member_name: None });
+                        if results.len() >= limit {
+                            continue;
+                        }
+                    }
                 }
-            } else if search_mode == "Member" {
-                let type_lower = obj.type_name.to_lowercase();
-                if type_lower.contains("class") || type_lower.contains("struct") {
-                    if let (Some(proc), Some(np)) = (&process, &name_pool) {
-                        let mut child_addr = proc.memory.try_read_pointer(obj.address.wrapping_add(offsets.member)).unwrap_or(0);
-                        let mut safety = 0;
-                        while child_addr > 0x10000 && safety < 2000 {
-                            safety += 1;
-                            let child_name_id = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
-                            let child_name = np.get_name(proc, child_name_id as u32).unwrap_or_default();
-
-                            if child_name.to_lowercase().contains(&query_lower) {
-                                results.push(GlobalSearchResult { package_name: pkg_name.clone(), object_name: obj.name.clone(), type_name: obj.type_name.clone(), address: obj.address, member_name: Some(child_name) });
-                                if results.len() >= limit {
-                                    break;
+
+                if search_mode == "Member" || search_mode == "Regex" {
+                    // No index yet (e.g. rebuild_index hasn't been called), or regex mode (which
+                    // the index doesn't support): fall back to the live-memory walk.
+                    let type_lower = obj.type_name.to_lowercase();
+                    if type_lower.contains("class") || type_lower.contains("struct") {
+                        if let (Some(proc), Some(np)) = (&process, &name_pool) {
+                            let mut child_addr = proc.memory.try_read_pointer(obj.address.wrapping_add(offsets.member)).unwrap_or(0);
+                            let mut safety = 0;
+                            while child_addr > 0x10000 && safety < 2000 {
+                                safety += 1;
+                                let child_name_id = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+                                let child_name = np.get_name(proc, child_name_id as u32).unwrap_or_default();
+
+                                if matcher.is_match(&child_name) {
+                                    results.push(GlobalSearchResult { package_name: pkg_name.clone(), object_name: obj.name.clone(), type_name: obj.type_name.clone(), address: obj.address, member_name: Some(child_name) });
+                                    if results.len() >= limit {
+                                        break;
+                                    }
                                 }
+
+                                child_addr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
                             }
+                        }
+                    } else if search_mode == "Regex" && type_lower.contains("function") {
+                        // Regex mode additionally matches function-parameter names.
+                        if let (Some(proc), Some(np)) = (&process, &name_pool) {
+                            let mut param_addr = proc.memory.try_read_pointer(obj.address.wrapping_add(offsets.funct_para)).unwrap_or(0);
+                            let mut safety = 0;
+                            while param_addr > 0x10000 && safety < 200 {
+                                safety += 1;
+                                let param_name_id = proc.memory.try_read::<i32>(param_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+                                let param_name = np.get_name(proc, param_name_id as u32).unwrap_or_default();
+
+                                if !param_name.is_empty() && param_name != "ReturnValue" && matcher.is_match(&param_name) {
+                                    results.push(GlobalSearchResult { package_name: pkg_name.clone(), object_name: obj.name.clone(), type_name: obj.type_name.clone(), address: obj.address, member_name: Some(param_name) });
+                                    if results.len() >= limit {
+                                        break;
+                                    }
+                                }
 
-                            child_addr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+                                param_addr = proc.memory.try_read_pointer(param_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+                            }
                         }
                     }
                 }
@@ -640,40 +904,126 @@ pub struct InstanceSearchResult {
     pub object_name: String,
 }
 
+#[derive(Clone, serde::Serialize)]
+struct InstanceResolveProgressPayload {
+    resolved: usize,
+    total_hits: usize,
+}
+
 #[tauri::command]
-pub async fn search_object_instances(state: State<'_, AppState>, object_address: String) -> Result<Vec<InstanceSearchResult>, String> {
-    let start_time = std::time::Instant::now();
+pub async fn search_object_instances(app_handle: tauri::AppHandle, state: State<'_, AppState>, object_address: String) -> Result<Vec<InstanceSearchResult>, String> {
     let addr = u64::from_str_radix(object_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid address format")?;
     let signature = addr.to_le_bytes().iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ");
 
-    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
-    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+    let process = state.process.lock().unwrap().clone().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("Name pool not valid")?.clone()
+    };
+    let obj_mgr = Arc::clone(&state.object_manager);
+    let cancel_flag = Arc::clone(&state.scan_cancel);
+    cancel_flag.store(false, std::sync::atomic::Ordering::Relaxed);
+    let offsets = state.active_offsets();
 
-    // In Unreal, user memory usually doesn't exceed 0x7FFFFFFFFFFF
-    let hits = crate::backend::os::scanner::Scanner::scan(&proc.memory, 0x0, 0x7FFFFFFFFFFF, &signature).map_err(|e| format!("Scan failed: {}", e))?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let start_time = std::time::Instant::now();
+
+        // In Unreal, user memory usually doesn't exceed 0x7FFFFFFFFFFF.
+        // The target is a fixed 8-byte-aligned pointer, not an arbitrary AOB, so the region
+        // scan steps 8 bytes at a time and reports progress so the UI can cancel mid-scan.
+        let hits = crate::backend::os::scanner::Scanner::scan_aligned_cancellable(&process.memory, 0x0, 0x7FFFFFFFFFFF, &signature, 8, &app_handle, "instance-scan-progress", &cancel_flag)
+            .map_err(|e| format!("Scan failed: {}", e))?;
+
+        // Resolving each hit is itself a handful of memory reads (GetBasicInfo tries two paths,
+        // each following a few pointers), so with tens of thousands of hits this loop used to be
+        // the slow part of the whole scan. `try_save_object`'s cache is a `DashMap`, so fanning
+        // this out across rayon's pool is safe the same way the region scan itself already is.
+        use rayon::prelude::*;
+        let resolved = std::sync::atomic::AtomicUsize::new(0);
+        let total_hits = hits.len();
+
+        let results: Vec<InstanceSearchResult> = hits
+            .into_par_iter()
+            .filter_map(|hit| {
+                if cancel_flag.load(std::sync::atomic::Ordering::Relaxed) {
+                    return None;
+                }
 
-    let mut results = Vec::new();
-    let obj_mgr = &state.object_manager;
+                let instance_addr = hit.saturating_sub(0x10);
+                let found = obj_mgr.try_save_object(instance_addr, &process, &name_pool, &offsets, 0, 5).filter(|obj_data| obj_data.name != "InvalidName" && obj_data.name != "None").map(|obj_data| InstanceSearchResult { instance_address: format!("0x{:X}", instance_addr), object_name: obj_data.name });
 
-    let name_pool_lock = state.name_pool.lock().map_err(|_| "Name pool lock failed")?;
-    let name_pool = name_pool_lock.as_ref().ok_or("Name pool not valid")?;
+                let done = resolved.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+                if done % 256 == 0 || done == total_hits {
+                    app_handle.emit("instance-scan-resolve-progress", InstanceResolveProgressPayload { resolved: done, total_hits }).ok();
+                }
 
-    let offsets = crate::backend::unreal::offsets::UEOffset::default();
+                found
+            })
+            .collect();
 
-    // Resolve hits into concrete instances
-    for hit in hits {
-        let instance_addr = hit.saturating_sub(0x10);
+        println!("[search_object_instances] Found {} instances in {:?}", results.len(), start_time.elapsed());
+        Ok(results)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
 
-        // Dynamically parse the object instance instead of relying on class address cache
-        if let Some(obj_data) = obj_mgr.try_save_object(instance_addr, proc, name_pool, &offsets, 0, 5) {
-            if obj_data.name != "InvalidName" && obj_data.name != "None" {
-                results.push(InstanceSearchResult { instance_address: format!("0x{:X}", instance_addr), object_name: obj_data.name });
-            }
-        }
-    }
+/// Requests cancellation of an in-flight `search_object_instances` scan; the scan checks this
+/// flag between regions/hits and stops early instead of running to completion.
+#[tauri::command]
+pub fn cancel_instance_scan(state: State<'_, AppState>) {
+    state.scan_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+}
 
-    println!("[search_object_instances] Found {} instances in {:?}", results.len(), start_time.elapsed());
-    Ok(results)
+#[derive(serde::Serialize)]
+pub struct FNameMatch {
+    pub id: u32,
+    pub name: String,
+}
+
+/// Resolves through `FNamePool`'s reverse atom-table index instead of scanning the pool — only
+/// ids something has already resolved (via `get_name`) are searchable this way, but that covers
+/// every object/member name the dump has touched so far.
+#[tauri::command]
+pub fn find_fnames_by_name(state: State<'_, AppState>, substring: String) -> Result<Vec<FNameMatch>, String> {
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("Name pool not valid")?.clone()
+    };
+
+    Ok(name_pool.find_ids_by_name(&substring).into_iter().map(|(id, name)| FNameMatch { id, name }).collect())
+}
+
+#[derive(serde::Serialize)]
+pub struct ObjectNameMatch {
+    pub address: String, // Hex string
+    pub object_name: String,
+    pub type_name: String,
+}
+
+/// Same idea as `find_fnames_by_name`, but matches against already-cached `ObjectManager`
+/// entries instead of raw FName ids: the reverse index narrows "which strings match" in O(names
+/// resolved so far) and this just filters the object cache down to those strings, rather than
+/// re-deriving the name span's live memory for every cached object.
+#[tauri::command]
+pub fn find_objects_by_name(state: State<'_, AppState>, substring: String) -> Result<Vec<ObjectNameMatch>, String> {
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("Name pool not valid")?.clone()
+    };
+    let obj_mgr = &state.object_manager;
+
+    let matched_names: std::collections::HashSet<String> = name_pool.find_ids_by_name(&substring).into_iter().map(|(_, name)| name).collect();
+
+    Ok(obj_mgr
+        .cache_by_address
+        .iter()
+        .filter(|entry| matched_names.contains(&entry.value().name))
+        .map(|entry| {
+            let obj = entry.value();
+            ObjectNameMatch { address: format!("0x{:X}", obj.address), object_name: obj.name.clone(), type_name: obj.type_name.clone() }
+        })
+        .collect())
 }
 
 #[derive(serde::Serialize)]
@@ -693,7 +1043,7 @@ pub async fn add_inspector(state: State<'_, AppState>, instance_address: String)
 
     let name_pool_lock = state.name_pool.lock().map_err(|_| "Name pool lock failed")?;
     let name_pool = name_pool_lock.as_ref().ok_or("Name pool not valid")?;
-    let offsets = crate::backend::unreal::offsets::UEOffset::default();
+    let offsets = state.active_offsets();
 
     let mut hierarchy = Vec::new();
 
@@ -715,7 +1065,7 @@ pub async fn add_inspector(state: State<'_, AppState>, instance_address: String)
     Ok(hierarchy)
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, Clone)]
 pub struct InstancePropertyInfo {
     pub property_name: String,
     pub property_type: String,
@@ -726,10 +1076,23 @@ pub struct InstancePropertyInfo {
     pub is_object: bool,
     pub object_instance_address: String,
     pub object_class_address: String,
+    /// Nesting level: 0 for a class's own members, 1+ for `StructProperty` fields and
+    /// `ArrayProperty` elements, so the frontend can render an indented/expandable tree.
+    pub depth: usize,
+    /// `StructProperty` fields, or `ArrayProperty` elements (named "[i]"), nested one level in.
+    pub children: Vec<InstancePropertyInfo>,
 }
 
+/// Caps how many `TArray` elements `get_instance_details` decodes per array, so a
+/// multi-million-entry array can't blow up the response or the walk time.
+const MAX_ARRAY_ELEMENTS: usize = 50;
+
+/// Caps `StructProperty` recursion depth, mirroring the `safety` counters already used for
+/// linked-list walks in this file (cyclic struct graphs shouldn't happen, but don't trust it).
+const MAX_PROPERTY_DEPTH: usize = 8;
+
 #[tauri::command]
-pub async fn get_instance_details(state: State<'_, AppState>, instance_address: String, class_address: String) -> Result<Vec<InstancePropertyInfo>, String> {
+pub async fn get_instance_details(state: State<'_, AppState>, instance_address: String, class_address: String, format_overrides: Option<std::collections::HashMap<String, String>>) -> Result<Vec<InstancePropertyInfo>, String> {
     let inst_addr = usize::from_str_radix(instance_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid instance address")?;
     let class_addr = usize::from_str_radix(class_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid class address")?;
 
@@ -740,14 +1103,27 @@ pub async fn get_instance_details(state: State<'_, AppState>, instance_address:
     let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
     let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
 
-    let offsets = crate::backend::unreal::offsets::UEOffset::default();
+    let offsets = state.active_offsets();
 
     // Validate class
     if !obj_mgr.cache_by_address.contains_key(&class_addr) {
         return Err("Class address not valid".to_string());
     }
 
+    let format_overrides = format_overrides.unwrap_or_default();
+    Ok(walk_instance_properties(proc, obj_mgr, name_pool, &offsets, inst_addr, class_addr, 0, &format_overrides))
+}
+
+/// Walks `class_addr`'s ChildProperty chain and decodes each member's live value at
+/// `inst_addr + offset`. Recurses one level in for `StructProperty` (into the nested
+/// `UScriptStruct`'s own member chain, `inst_addr` rebased to the struct's embedded address)
+/// and for `ArrayProperty` (synthetic `"[i]"` children, one per element) — both reported via
+/// `children`/`depth` so the frontend can render an expandable tree.
+fn walk_instance_properties(proc: &Process, obj_mgr: &crate::backend::unreal::object_array::ObjectManager, name_pool: &crate::backend::unreal::name_pool::FNamePool, offsets: &crate::backend::unreal::offsets::UEOffset, inst_addr: usize, class_addr: usize, depth: usize, format_overrides: &std::collections::HashMap<String, String>) -> Vec<InstancePropertyInfo> {
     let mut results = Vec::new();
+    if depth > MAX_PROPERTY_DEPTH {
+        return results;
+    }
 
     // Walk the properties of the class
     let mut child_addr = proc.memory.try_read_pointer(class_addr.wrapping_add(offsets.member)).unwrap_or(0);
@@ -774,11 +1150,12 @@ pub async fn get_instance_details(state: State<'_, AppState>, instance_address:
             let mut object_instance_address = String::new();
             let mut object_class_address = String::new();
             let mut unassigned_live_value: Option<String> = None;
+            let mut children: Vec<InstancePropertyInfo> = Vec::new();
 
             if type_lower.contains("objectproperty") || type_lower.contains("classproperty") {
                 is_object = true;
                 if prop_0 > 0x10000 {
-                    if let Some(sub_obj) = obj_mgr.try_save_object(prop_0, proc, name_pool, &offsets, 0, 5) {
+                    if let Some(sub_obj) = obj_mgr.try_save_object(prop_0, proc, name_pool, offsets, 0, 5) {
                         sub_type = sub_obj.name.clone();
                     }
                 }
@@ -786,7 +1163,7 @@ pub async fn get_instance_details(state: State<'_, AppState>, instance_address:
                 let object_ptr = proc.memory.try_read_pointer(actual_memory_addr).unwrap_or(0);
                 if object_ptr > 0x10000 {
                     object_instance_address = format!("0x{:X}", object_ptr);
-                    if let Some(inst_obj) = obj_mgr.try_save_object(object_ptr, proc, name_pool, &offsets, 0, 5) {
+                    if let Some(inst_obj) = obj_mgr.try_save_object(object_ptr, proc, name_pool, offsets, 0, 5) {
                         sub_type = inst_obj.type_name.clone();
                         let c_addr = proc.memory.try_read_pointer(object_ptr.wrapping_add(offsets.class)).unwrap_or(0);
                         object_class_address = format!("0x{:X}", c_addr);
@@ -799,47 +1176,68 @@ pub async fn get_instance_details(state: State<'_, AppState>, instance_address:
             } else if type_lower.contains("enumproperty") {
                 let enum_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(0x40)).unwrap_or(0); // Optional deeper enum reading
                 if enum_ptr > 0x10000 {
-                    if let Some(sub_obj) = obj_mgr.try_save_object(enum_ptr, proc, name_pool, &offsets, 0, 5) {
+                    if let Some(sub_obj) = obj_mgr.try_save_object(enum_ptr, proc, name_pool, offsets, 0, 5) {
                         sub_type = sub_obj.name.clone();
                     }
                 }
+            } else if type_lower.contains("structproperty") {
+                let struct_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.struct_name)).unwrap_or(0);
+                if struct_ptr > 0x10000 {
+                    if let Some(struct_obj) = obj_mgr.try_save_object(struct_ptr, proc, name_pool, offsets, 0, 5) {
+                        sub_type = struct_obj.name.clone();
+                    }
+                }
             }
 
             if !child_name.is_empty() && !child_type.is_empty() {
                 let actual_memory_addr = inst_addr.wrapping_add(offset_val);
 
-                // Read Live Value intelligently based on core types
+                // Read Live Value: scalar property kinds go through the `Conversion` registry so
+                // the caller can override how a given field displays (e.g. an int as hex, an
+                // int64 as a timestamp); composite kinds (object/struct/array) keep their own
+                // dedicated handling above since a single rendered string can't represent them.
+                let conversion = format_overrides.get(&child_name).and_then(|spec| crate::backend::unreal::conversion::Conversion::parse(spec)).unwrap_or_else(|| crate::backend::unreal::conversion::Conversion::default_for(&child_type));
+
                 let live_value = if let Some(val) = unassigned_live_value {
                     val
                 } else if type_lower.contains("boolproperty") {
                     let bitmask = proc.memory.try_read::<u8>(child_addr.wrapping_add(offsets.bit_mask)).unwrap_or(0);
                     let memory_byte = proc.memory.try_read::<u8>(actual_memory_addr).unwrap_or(0);
                     let is_true = (memory_byte & bitmask) > 0;
-                    if is_true {
-                        "True".to_string()
-                    } else {
-                        "False".to_string()
-                    }
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Bool(is_true))
                 } else if type_lower.contains("nameproperty") {
                     let name_id = proc.memory.try_read::<i32>(actual_memory_addr).unwrap_or(0);
                     let name_str = name_pool.get_name(proc, name_id as u32).unwrap_or_default();
-                    if name_str.is_empty() {
-                        "None".to_string()
-                    } else {
-                        name_str
-                    }
+                    let name_str = if name_str.is_empty() { "None".to_string() } else { name_str };
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Str(name_str))
                 } else if type_lower.contains("intproperty") || type_lower.contains("int32") {
                     let val = proc.memory.try_read::<i32>(actual_memory_addr).unwrap_or(0);
-                    val.to_string()
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Int(val as i64))
+                } else if type_lower.contains("int64property") {
+                    let val = proc.memory.try_read::<i64>(actual_memory_addr).unwrap_or(0);
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Int(val))
                 } else if type_lower.contains("floatproperty") {
                     let val = proc.memory.try_read::<f32>(actual_memory_addr).unwrap_or(0.0);
-                    format!("{:.3}", val)
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Float(val as f64))
                 } else if type_lower.contains("doubleproperty") {
                     let val = proc.memory.try_read::<f64>(actual_memory_addr).unwrap_or(0.0);
-                    format!("{:.5}", val)
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Float(val))
                 } else if type_lower.contains("byteproperty") {
                     let val = proc.memory.try_read::<u8>(actual_memory_addr).unwrap_or(0);
-                    val.to_string()
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Int(val as i64))
+                } else if type_lower.contains("strproperty") || type_lower.contains("textproperty") {
+                    let str_val = decode_fstring(proc, actual_memory_addr).unwrap_or_default();
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Str(str_val))
+                } else if type_lower.contains("structproperty") {
+                    let struct_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.struct_name)).unwrap_or(0);
+                    if struct_ptr > 0x10000 {
+                        children = walk_instance_properties(proc, obj_mgr, name_pool, offsets, actual_memory_addr, struct_ptr, depth + 1, format_overrides);
+                    }
+                    conversion.render(&crate::backend::unreal::conversion::RawValue::Int(children.len() as i64))
+                } else if type_lower.contains("arrayproperty") {
+                    let (element_count, array_children) = decode_array_elements(proc, obj_mgr, name_pool, offsets, child_addr, actual_memory_addr, depth + 1);
+                    children = array_children;
+                    format!("[{} element(s)]", element_count)
                 } else {
                     format!("0x{:X}", proc.memory.try_read_pointer(actual_memory_addr).unwrap_or(0))
                 };
@@ -852,32 +1250,914 @@ pub async fn get_instance_details(state: State<'_, AppState>, instance_address:
                     format!("{:X}", offset_val)
                 };
 
-                results.push(InstancePropertyInfo { property_name: child_name, property_type: child_type, offset: offset_str, sub_type, memory_address: format!("0x{:X}", actual_memory_addr), live_value, is_object, object_instance_address, object_class_address });
+                results.push(InstancePropertyInfo { property_name: child_name, property_type: child_type, offset: offset_str, sub_type, memory_address: format!("0x{:X}", actual_memory_addr), live_value, is_object, object_instance_address, object_class_address, depth, children });
+            }
+        }
+        child_addr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+    }
+
+    results
+}
+
+/// Decodes an `FString`/`FText`-backed `{ TCHAR* Data (+0x0); int32 Num (+0x8); int32 Max (+0xC) }`
+/// at `addr`. Bails (returns `None`) if `Data` isn't a plausible pointer or `Num` is zero/absurd,
+/// rather than reading garbage.
+fn decode_fstring(proc: &Process, addr: usize) -> Option<String> {
+    let data_ptr = proc.memory.try_read_pointer(addr)?;
+    let num = proc.memory.try_read::<i32>(addr.wrapping_add(0x8)).unwrap_or(0);
+    if data_ptr <= 0x10000 || num <= 0 || num > 0x10000 {
+        return None;
+    }
+
+    let bytes = proc.memory.read_bytes(data_ptr, num as usize * 2).ok()?;
+    let wide: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+    Some(String::from_utf16_lossy(&wide).trim_end_matches('\0').to_string())
+}
+
+/// Decodes a single element of a `TArray` backing an `ArrayProperty`, dispatching on the
+/// array's `Inner` property type the same way `walk_instance_properties` dispatches on a
+/// top-level property's type. `inner_ptr` is the `Inner` `FProperty*` itself, needed for
+/// `StructProperty` elements (to resolve their `UScriptStruct*`) and for reading nested
+/// `ElementSize`/type if ever needed by a future element kind.
+fn format_array_element(proc: &Process, obj_mgr: &crate::backend::unreal::object_array::ObjectManager, name_pool: &crate::backend::unreal::name_pool::FNamePool, offsets: &crate::backend::unreal::offsets::UEOffset, inner_type_lower: &str, element_addr: usize, inner_ptr: usize, depth: usize) -> (String, Vec<InstancePropertyInfo>) {
+    if inner_type_lower.contains("boolproperty") {
+        let byte = proc.memory.try_read::<u8>(element_addr).unwrap_or(0);
+        (if byte != 0 { "True".to_string() } else { "False".to_string() }, Vec::new())
+    } else if inner_type_lower.contains("nameproperty") {
+        let name_id = proc.memory.try_read::<i32>(element_addr).unwrap_or(0);
+        let name_str = name_pool.get_name(proc, name_id as u32).unwrap_or_default();
+        (if name_str.is_empty() { "None".to_string() } else { name_str }, Vec::new())
+    } else if inner_type_lower.contains("strproperty") || inner_type_lower.contains("textproperty") {
+        (decode_fstring(proc, element_addr).unwrap_or_default(), Vec::new())
+    } else if inner_type_lower.contains("intproperty") || inner_type_lower.contains("int32") {
+        (proc.memory.try_read::<i32>(element_addr).unwrap_or(0).to_string(), Vec::new())
+    } else if inner_type_lower.contains("floatproperty") {
+        (format!("{:.3}", proc.memory.try_read::<f32>(element_addr).unwrap_or(0.0)), Vec::new())
+    } else if inner_type_lower.contains("doubleproperty") {
+        (format!("{:.5}", proc.memory.try_read::<f64>(element_addr).unwrap_or(0.0)), Vec::new())
+    } else if inner_type_lower.contains("byteproperty") {
+        (proc.memory.try_read::<u8>(element_addr).unwrap_or(0).to_string(), Vec::new())
+    } else if inner_type_lower.contains("objectproperty") || inner_type_lower.contains("classproperty") {
+        let object_ptr = proc.memory.try_read_pointer(element_addr).unwrap_or(0);
+        if object_ptr > 0x10000 {
+            if let Some(inst_obj) = obj_mgr.try_save_object(object_ptr, proc, name_pool, offsets, 0, 5) {
+                return (inst_obj.name.clone(), Vec::new());
             }
         }
+        (format!("0x{:X}", object_ptr), Vec::new())
+    } else if inner_type_lower.contains("structproperty") {
+        let struct_ptr = proc.memory.try_read_pointer(inner_ptr.wrapping_add(offsets.struct_name)).unwrap_or(0);
+        if struct_ptr > 0x10000 {
+            let children = walk_instance_properties(proc, obj_mgr, name_pool, offsets, element_addr, struct_ptr, depth, &std::collections::HashMap::new());
+            let rendered = crate::backend::unreal::conversion::Conversion::Struct.render(&crate::backend::unreal::conversion::RawValue::Int(children.len() as i64));
+            return (rendered, children);
+        }
+        (format!("0x{:X}", element_addr), Vec::new())
+    } else {
+        (format!("0x{:X}", proc.memory.try_read_pointer(element_addr).unwrap_or(0)), Vec::new())
+    }
+}
+
+/// Decodes a `TArray`-backed `ArrayProperty`'s `{ void* Data (+0x0); int32 Num (+0x8); int32 Max
+/// (+0xC) }` at `addr`, enumerating up to `MAX_ARRAY_ELEMENTS` elements from `child_addr` (the
+/// owning `FArrayProperty`)'s `Inner` property + `ElementSize`. Returns the *real* element count
+/// (even if it was capped) alongside the decoded children.
+fn decode_array_elements(proc: &Process, obj_mgr: &crate::backend::unreal::object_array::ObjectManager, name_pool: &crate::backend::unreal::name_pool::FNamePool, offsets: &crate::backend::unreal::offsets::UEOffset, child_addr: usize, addr: usize, depth: usize) -> (usize, Vec<InstancePropertyInfo>) {
+    decode_array_window(proc, obj_mgr, name_pool, offsets, child_addr, addr, depth, 0, MAX_ARRAY_ELEMENTS)
+}
+
+/// Paginated sibling of `decode_array_elements`, windowing on `[offset, offset + count)` instead
+/// of always starting at element 0 — used by `get_array_elements` so a frontend can page through
+/// a `TArray` larger than `MAX_ARRAY_ELEMENTS` instead of only ever seeing its first window.
+/// `count` is still clamped to `MAX_ARRAY_ELEMENTS` per call. Returns the *real* element count
+/// (never capped) alongside whichever slice of it was actually decoded.
+fn decode_array_window(proc: &Process, obj_mgr: &crate::backend::unreal::object_array::ObjectManager, name_pool: &crate::backend::unreal::name_pool::FNamePool, offsets: &crate::backend::unreal::offsets::UEOffset, child_addr: usize, addr: usize, depth: usize, offset: usize, count: usize) -> (usize, Vec<InstancePropertyInfo>) {
+    let data_ptr = proc.memory.try_read_pointer(addr).unwrap_or(0);
+    let num = proc.memory.try_read::<i32>(addr.wrapping_add(0x8)).unwrap_or(0);
+    if data_ptr <= 0x10000 || num <= 0 {
+        return (0, Vec::new());
+    }
+
+    let element_count = num as usize;
+    if offset >= element_count {
+        return (element_count, Vec::new());
+    }
+
+    let inner_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.array)).unwrap_or(0);
+    if inner_ptr <= 0x10000 {
+        return (element_count, Vec::new());
+    }
+
+    let element_size = proc.memory.try_read::<i32>(inner_ptr.wrapping_add(offsets.prop_size)).unwrap_or(0);
+    if element_size <= 0 {
+        return (element_count, Vec::new());
+    }
+
+    let inner_type_ptr = proc.memory.try_read_pointer(inner_ptr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+    let inner_type_id = proc.memory.try_read::<i32>(inner_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+    let inner_type = name_pool.get_name(proc, inner_type_id as u32).unwrap_or_default();
+    let inner_type_lower = inner_type.to_lowercase();
+
+    let window_end = offset.saturating_add(count.min(MAX_ARRAY_ELEMENTS)).min(element_count);
+    let mut children = Vec::with_capacity(window_end - offset);
+
+    for i in offset..window_end {
+        let element_addr = data_ptr.wrapping_add(i * element_size as usize);
+        let (live_value, element_children) = format_array_element(proc, obj_mgr, name_pool, offsets, &inner_type_lower, element_addr, inner_ptr, depth + 1);
+
+        children.push(InstancePropertyInfo {
+            property_name: format!("[{}]", i),
+            property_type: inner_type.clone(),
+            offset: format!("{:X}", i * element_size as usize),
+            sub_type: String::new(),
+            memory_address: format!("0x{:X}", element_addr),
+            live_value,
+            is_object: inner_type_lower.contains("objectproperty") || inner_type_lower.contains("classproperty"),
+            object_instance_address: String::new(),
+            object_class_address: String::new(),
+            depth,
+            children: element_children,
+        });
+    }
+
+    (element_count, children)
+}
+
+#[derive(serde::Serialize)]
+pub struct PagedArrayElements {
+    pub elements: Vec<InstancePropertyInfo>,
+    pub total_count: usize,
+}
+
+/// Frontend-facing pagination over a single `ArrayProperty` instance, built on top of
+/// `decode_array_window` — lets a caller page through a `TArray` larger than
+/// `MAX_ARRAY_ELEMENTS` instead of only ever seeing what `get_instance_details` decoded on its
+/// first pass. `array_property_address` is the owning `FArrayProperty*` (`child_addr` elsewhere in
+/// this file, needed for `Inner`/`ElementSize`); `array_instance_address` is the `TArray` itself.
+/// An out-of-range `offset` comes back as an empty `elements` with the real `total_count`, never
+/// an error.
+///
+/// `MapProperty`/`SetProperty` are rejected outright: `decode_array_window` only decodes the
+/// `TArray` layout, so map and set elements are not paged by this command.
+#[tauri::command]
+pub fn get_array_elements(state: State<'_, AppState>, array_property_address: String, array_instance_address: String, offset: usize, count: usize) -> Result<PagedArrayElements, String> {
+    let child_addr = usize::from_str_radix(array_property_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid array property address")?;
+    let array_addr = usize::from_str_radix(array_instance_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid array instance address")?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let obj_mgr = &state.object_manager;
+    let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
+    let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
+
+    let offsets = state.active_offsets();
+
+    let child_type_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+    let child_type_id = proc.memory.try_read::<i32>(child_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+    let child_type = name_pool.get_name(proc, child_type_id as u32).unwrap_or_default();
+    let type_lower = child_type.to_lowercase();
+
+    if type_lower.contains("mapproperty") || type_lower.contains("setproperty") {
+        return Err(format!("'{}' elements can't be paginated: only ArrayProperty elements are paged", child_type));
+    }
+    if !type_lower.contains("arrayproperty") {
+        return Err(format!("'{}' is not an indexable container property", child_type));
+    }
+
+    let (total_count, elements) = decode_array_window(proc, obj_mgr, name_pool, &offsets, child_addr, array_addr, 0, offset, count);
+    Ok(PagedArrayElements { elements, total_count })
+}
+
+#[derive(serde::Serialize)]
+pub struct MemberValueInfo {
+    pub name: String,
+    pub type_name: String,
+    pub value: String,
+    /// The member's raw bytes as hex, independent of `value`'s typed decode — so a mis-decoded
+    /// or undecodable property is still inspectable.
+    pub raw_hex: String,
+}
+
+/// Walks a live instance's class member chain and decodes each property's bytes into a typed
+/// `PropertyValue`, instead of only reporting member *names* the way `global_search` does.
+#[tauri::command]
+pub async fn read_instance_members(state: State<'_, AppState>, instance_address: String) -> Result<Vec<MemberValueInfo>, String> {
+    let inst_addr = usize::from_str_radix(instance_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid instance address")?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let obj_mgr = &state.object_manager;
+    let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
+    let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
+
+    let offsets = state.active_offsets();
+
+    // ClassPrivate lives at a fixed offset on every UObject instance (see `add_inspector`).
+    let class_addr = proc.memory.try_read_pointer(inst_addr.wrapping_add(0x10)).unwrap_or(0);
+    if class_addr < 0x10000 {
+        return Err("Could not resolve the instance's class pointer".to_string());
+    }
+
+    let mut results = Vec::new();
+    let mut child_addr = proc.memory.try_read_pointer(class_addr.wrapping_add(offsets.member)).unwrap_or(0);
+    let mut safety = 0;
+
+    while child_addr > 0x10000 && safety < 2000 {
+        safety += 1;
+
+        let child_name_id = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+        let child_name = name_pool.get_name(proc, child_name_id as u32).unwrap_or_default();
+
+        let type_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+        let type_id = proc.memory.try_read::<i32>(type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+        let child_type = name_pool.get_name(proc, type_id as u32).unwrap_or_default();
+
+        if !child_name.is_empty() && child_type.to_lowercase().contains("property") {
+            let child_offset = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.offset)).unwrap_or(0) as usize;
+            let prop_size = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.prop_size)).unwrap_or(0);
+            let bit_mask = proc.memory.try_read::<u8>(child_addr.wrapping_add(offsets.bit_mask)).unwrap_or(0);
+
+            let member = crate::backend::unreal::member_value::MemberDescriptor { name: child_name.clone(), type_name: child_type.clone(), offset: child_offset, prop_size, bit_mask };
+            let value = crate::backend::unreal::member_value::read_member_value(proc, name_pool, obj_mgr, &offsets, inst_addr, &member);
+
+            let raw_hex = crate::backend::unreal::member_value::raw_hex(proc, inst_addr, &member);
+            results.push(MemberValueInfo { name: child_name, type_name: child_type, value: value.to_string(), raw_hex });
+        }
+
         child_addr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
     }
 
     Ok(results)
 }
 
+/// Like `read_instance_members`, but takes the class/struct address explicitly instead of
+/// deriving it from the instance's `ClassPrivate` pointer. This is what makes it usable as a
+/// live memory viewer for nested `StructProperty` members: decode a member as `PropertyKind::Struct`
+/// (see `member_value.rs`), get back the embedded struct's address, and call this command again
+/// with that address as `instance_address` and the struct's sub-type as `class_address`.
+#[tauri::command]
+pub async fn read_instance_values(state: State<'_, AppState>, instance_address: String, class_address: String) -> Result<Vec<MemberValueInfo>, String> {
+    let inst_addr = usize::from_str_radix(instance_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid instance address")?;
+    let class_addr = usize::from_str_radix(class_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid class address")?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let obj_mgr = &state.object_manager;
+    let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
+    let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
+
+    let offsets = state.active_offsets();
+
+    if !obj_mgr.cache_by_address.contains_key(&class_addr) {
+        return Err("Class address not valid".to_string());
+    }
+
+    let mut results = Vec::new();
+    let mut child_addr = proc.memory.try_read_pointer(class_addr.wrapping_add(offsets.member)).unwrap_or(0);
+    let mut safety = 0;
+
+    while child_addr > 0x10000 && safety < 2000 {
+        safety += 1;
+
+        let child_name_id = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+        let child_name = name_pool.get_name(proc, child_name_id as u32).unwrap_or_default();
+
+        let type_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+        let type_id = proc.memory.try_read::<i32>(type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+        let child_type = name_pool.get_name(proc, type_id as u32).unwrap_or_default();
+
+        if !child_name.is_empty() && child_type.to_lowercase().contains("property") {
+            let child_offset = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.offset)).unwrap_or(0) as usize;
+            let prop_size = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.prop_size)).unwrap_or(0);
+            let bit_mask = proc.memory.try_read::<u8>(child_addr.wrapping_add(offsets.bit_mask)).unwrap_or(0);
+
+            let member = crate::backend::unreal::member_value::MemberDescriptor { name: child_name.clone(), type_name: child_type.clone(), offset: child_offset, prop_size, bit_mask };
+            let value = crate::backend::unreal::member_value::read_member_value(proc, name_pool, obj_mgr, &offsets, inst_addr, &member);
+
+            let raw_hex = crate::backend::unreal::member_value::raw_hex(proc, inst_addr, &member);
+            results.push(MemberValueInfo { name: child_name, type_name: child_type, value: value.to_string(), raw_hex });
+        }
+
+        child_addr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+    }
+
+    Ok(results)
+}
+
+/// Performs the one-time indexing pass over every cached class/struct's member chain, so
+/// subsequent `global_search` Member queries are a cheap in-memory lookup instead of a live
+/// memory walk. Call again after re-parsing GUObjectArray, since class addresses may have moved.
+#[tauri::command]
+pub async fn rebuild_index(state: State<'_, AppState>) -> Result<usize, String> {
+    let process = state.process.lock().unwrap().clone().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let obj_mgr = Arc::clone(&state.object_manager);
+    let member_index = Arc::clone(&state.member_index);
+    let offsets = state.active_offsets();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let total = member_index.build(&obj_mgr, &process, &name_pool, &offsets);
+        println!("[rebuild_index] Indexed {} (class, member) pairs", total);
+        Ok(total)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Drops the member index, forcing `global_search` back onto the live-memory fallback until
+/// `rebuild_index` is called again. Needed whenever the class addresses or member offsets it
+/// was built from stop being valid (e.g. reattaching, or loading a different offset profile).
+#[tauri::command]
+pub fn invalidate_index(state: State<'_, AppState>) {
+    state.member_index.invalidate();
+}
+
+/// Exports the discovered class hierarchy (SuperStruct chain) and containment graph (Outer) as
+/// a Graphviz DOT document, so users can pipe it straight into `dot`/online viewers instead of
+/// reading a flat search list.
+#[tauri::command]
+pub fn export_class_graph(state: State<'_, AppState>, root_package: Option<String>) -> Result<String, String> {
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+    let offsets = state.active_offsets();
+
+    Ok(crate::backend::unreal::graph_export::export_class_graph(&state.object_manager, process, &offsets, root_package.as_deref()))
+}
+
+/// Complements `export_class_graph`'s whole-cache sweep with a single object's own
+/// Outer/Class/SuperStruct neighborhood, for when a user wants to see how one instance relates
+/// to its surroundings instead of the flattened `full_name` string.
+#[tauri::command]
+pub fn export_object_graph(state: State<'_, AppState>, address_str: String, depth: usize) -> Result<String, String> {
+    let root_address = usize::from_str_radix(address_str.trim_start_matches("0x"), 16).map_err(|_| "Invalid address format")?;
+
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let offsets = state.active_offsets();
+
+    Ok(crate::backend::unreal::graph_export::export_object_graph(&state.object_manager, process, &name_pool, &offsets, root_address, depth))
+}
+
+/// Builds a navigable object-*reference* graph starting from `address_str` — every
+/// `ObjectProperty`/`ClassProperty`/`WeakObjectProperty`/`InterfaceProperty` value (scalar or
+/// inside an object-typed array) reachable within `max_depth` hops — as opposed to
+/// `export_object_graph`'s fixed Outer/Class/SuperStruct neighborhood. Returned as structured
+/// nodes/edges rather than a DOT string so the frontend can render it interactively.
+#[tauri::command]
+pub fn build_object_reference_graph(state: State<'_, AppState>, address_str: String, max_depth: usize) -> Result<crate::backend::unreal::reference_graph::ObjectGraph, String> {
+    let root_address = usize::from_str_radix(address_str.trim_start_matches("0x"), 16).map_err(|_| "Invalid address format")?;
+
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let offsets = state.active_offsets();
+
+    Ok(crate::backend::unreal::reference_graph::build_object_graph(process, &state.object_manager, &name_pool, &offsets, root_address, max_depth))
+}
+
+/// Re-walks from `from_address_str` (same traversal `build_object_reference_graph` uses) and
+/// returns the property-name chain connecting it to `to_address_str`, or `None` if `to` isn't
+/// reachable within `max_depth` hops.
+#[tauri::command]
+pub fn find_object_reference_path(state: State<'_, AppState>, from_address_str: String, to_address_str: String, max_depth: usize) -> Result<Option<Vec<String>>, String> {
+    let from = usize::from_str_radix(from_address_str.trim_start_matches("0x"), 16).map_err(|_| "Invalid 'from' address format")?;
+    let to = usize::from_str_radix(to_address_str.trim_start_matches("0x"), 16).map_err(|_| "Invalid 'to' address format")?;
+
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let offsets = state.active_offsets();
+
+    let graph = crate::backend::unreal::reference_graph::build_object_graph(process, &state.object_manager, &name_pool, &offsets, from, max_depth);
+    Ok(crate::backend::unreal::reference_graph::find_paths(&graph, from, to))
+}
+
+/// "Who owns this?" — sweeps every object `ObjectManager` has already cached for one whose
+/// object-typed properties point at `address_str`, instead of following pointers forward from a
+/// root like `build_object_reference_graph` does.
+#[tauri::command]
+pub fn find_object_referencers(state: State<'_, AppState>, address_str: String) -> Result<Vec<crate::backend::unreal::reference_graph::GraphEdge>, String> {
+    let target = usize::from_str_radix(address_str.trim_start_matches("0x"), 16).map_err(|_| "Invalid address format")?;
+
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let offsets = state.active_offsets();
+
+    Ok(crate::backend::unreal::reference_graph::find_referencers(process, &state.object_manager, &name_pool, &offsets, target))
+}
+
+/// Decodes a `UFunction`'s Kismet bytecode (its `Script` array) into a readable instruction
+/// listing, so users can inspect what a Blueprint-callable function actually does instead of
+/// just its signature. See `kismet_disasm` for the opcode-by-opcode decode.
+#[tauri::command]
+pub fn get_function_disassembly(state: State<'_, AppState>, function_address: String) -> Result<Vec<crate::backend::unreal::kismet_disasm::DisassembledInstruction>, String> {
+    let address = usize::from_str_radix(function_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid function address")?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
+    let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
+
+    let offsets = state.active_offsets();
+    crate::backend::unreal::kismet_disasm::get_function_disassembly(proc, &state.object_manager, name_pool, &offsets, address)
+}
+
+/// Serializes the already-parsed classes, structs, and enums into the binary `.usmap` mappings
+/// format consumed by the UE modding ecosystem (FModel, UAssetGUI, CUE4Parse), so users get a
+/// portable artifact instead of a one-off UI dump.
+#[tauri::command]
+pub fn export_usmap(state: State<'_, AppState>) -> Result<Vec<u8>, String> {
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let offsets = state.active_offsets();
+
+    let bytes = crate::backend::unreal::usmap_export::export_usmap(&state.object_manager, process, &name_pool, &offsets);
+    println!("[export_usmap] Wrote {} bytes", bytes.len());
+    Ok(bytes)
+}
+
+#[derive(serde::Serialize)]
+pub struct SymbolExportResult {
+    /// `.debug_abbrev` section bytes.
+    pub debug_abbrev: Vec<u8>,
+    /// `.debug_info` section bytes.
+    pub debug_info: Vec<u8>,
+    /// Plain-text `address label` map, for tools that don't want to parse DWARF.
+    pub label_map: String,
+}
+
+/// Resolves `GUObjectArray`/`FNamePool`/`GWorld` the same way `show_base_address` does, then
+/// hands them to `SymbolExporter` alongside the active `UEOffset` so a debugger can load the
+/// dump with real symbols instead of raw addresses.
+#[tauri::command]
+pub fn export_symbols(state: State<'_, AppState>) -> Result<SymbolExportResult, String> {
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+
+    let fname_pool = BaseAddressDumper::get_fname_pool(process).map_err(|e| format!("Failed to get FNamePool: {}", e))?;
+    let guobject_array = BaseAddressDumper::get_guobject_array(process).map_err(|e| format!("Failed to get GUObjectArray: {}", e))?;
+    let gworld = BaseAddressDumper::get_gworld(process).map_err(|e| format!("Failed to get GWorld: {}", e))?;
+
+    let globals = vec![
+        crate::backend::unreal::symbol_export::ResolvedGlobal { name: "GUObjectArray", address: guobject_array },
+        crate::backend::unreal::symbol_export::ResolvedGlobal { name: "FNamePool", address: fname_pool },
+        crate::backend::unreal::symbol_export::ResolvedGlobal { name: "GWorld", address: gworld },
+    ];
+
+    let offsets = state.active_offsets();
+    let (debug_abbrev, debug_info) = crate::backend::unreal::symbol_export::SymbolExporter::export_dwarf(&offsets, &globals);
+    let label_map = crate::backend::unreal::symbol_export::SymbolExporter::export_label_map(&globals);
+
+    println!("[export_symbols] Wrote {} byte(s) of debug_info, {} global(s)", debug_info.len(), globals.len());
+    Ok(SymbolExportResult { debug_abbrev, debug_info, label_map })
+}
+
+#[derive(serde::Serialize)]
+pub struct ResolvedOffsetsResult {
+    pub offsets: crate::backend::unreal::offsets::UEOffset,
+    pub confidence: crate::backend::unreal::offset_resolver::OffsetConfidence,
+}
+
+/// Name the auto-resolved profile is saved and switched to by `resolve_offsets` — distinct from
+/// any profile a user has hand-saved via `save_offset_profile`, so re-running this command never
+/// clobbers one of those.
+const LIVE_RESOLVED_PROFILE_NAME: &str = "Live (Auto-Resolved)";
+
+/// Empirically re-derives `id`/`fname_index`/`class`/`outer`/`super_struct`/`member`/`prop_size`/
+/// `offset` from the attached process's own live GUObjectArray via `OffsetResolver`, using
+/// `BaseAddressDumper`'s signature scan (now built on `sigscan::Signature`) to find FNamePool and
+/// GUObjectArray in the first place — instead of trusting whatever offset profile happens to be
+/// active. Every other `UEOffset` field, and any of these eight that couldn't be validated, falls
+/// back to `UEOffset::default()`. Saves the result as the `LIVE_RESOLVED_PROFILE_NAME` profile and
+/// switches to it immediately, so `get_object_details` and every other parsing command read the
+/// resolved layout on their very next call instead of the stale active profile.
+#[tauri::command]
+pub fn resolve_offsets(state: State<'_, AppState>) -> Result<ResolvedOffsetsResult, String> {
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let process = process_lock.as_ref().ok_or("No process attached")?;
+
+    let fname_pool_addr = BaseAddressDumper::get_fname_pool(process)?;
+    let (guobject_addr, element_size) = BaseAddressDumper::get_guobject_array_with_element_size(process)?;
+
+    let base_offsets = state.active_offsets();
+    let name_pool = crate::backend::unreal::name_pool::FNamePool::new(fname_pool_addr, &base_offsets);
+
+    let (offsets, confidence) = crate::backend::unreal::offset_resolver::OffsetResolver::resolve(process, &name_pool, guobject_addr, element_size);
+
+    let path = crate::backend::unreal::offset_profile::default_profile_path();
+    state.offset_profiles.lock().unwrap().save(&path, LIVE_RESOLVED_PROFILE_NAME.to_string(), offsets)?;
+    *state.active_profile_name.lock().unwrap() = LIVE_RESOLVED_PROFILE_NAME.to_string();
+    state.member_index.invalidate();
+
+    Ok(ResolvedOffsetsResult { offsets, confidence })
+}
+
+/// Lists every offset profile currently loaded from `offset_profiles.toml`, so the UI can
+/// offer them in a dropdown alongside the one that's currently active.
+#[tauri::command]
+pub fn list_offset_profiles(state: State<'_, AppState>) -> Vec<String> {
+    state.offset_profiles.lock().unwrap().list()
+}
+
+/// Switches every subsequent parsing command over to a different previously-loaded profile.
+#[tauri::command]
+pub fn set_offset_profile(state: State<'_, AppState>, name: String) -> Result<(), String> {
+    if state.offset_profiles.lock().unwrap().get(&name).is_none() {
+        return Err(format!("Unknown offset profile '{}'", name));
+    }
+    *state.active_profile_name.lock().unwrap() = name;
+    // Cached member offsets belong to the profile that was active when they were indexed.
+    state.member_index.invalidate();
+    Ok(())
+}
+
+/// Persists the given `UEOffset` layout as a named profile in `offset_profiles.toml`, so new
+/// UE versions/obfuscated builds can be supported by editing a file instead of recompiling.
+#[tauri::command]
+pub fn save_offset_profile(state: State<'_, AppState>, name: String, offsets: crate::backend::unreal::offsets::UEOffset) -> Result<(), String> {
+    let path = crate::backend::unreal::offset_profile::default_profile_path();
+    state.offset_profiles.lock().unwrap().save(&path, name, offsets)
+}
+
+/// Installs the `ResolveStep` program `get_object_details` drives its sub-type resolution
+/// through, overriding `default_sub_type_steps` for builds that order `Property_0`/`Property_8`
+/// differently or store the type `FName` at a non-standard offset. Pass `None` to go back to
+/// the built-in default for the active offset profile.
+#[tauri::command]
+pub fn set_resolve_steps(state: State<'_, AppState>, steps: Option<Vec<crate::backend::unreal::resolve_step::ResolveStep>>) {
+    *state.resolve_steps.lock().unwrap() = steps;
+}
+
+#[derive(serde::Serialize)]
+pub struct GeneratedHeaderInfo {
+    pub package_name: String,
+    pub file_name: String,
+    pub content: String,
+}
+
+/// Reconstructs a compilable-looking C++ SDK (one header per package plus a master include)
+/// from the already-parsed object graph, reusing the same property/inheritance/enum/function
+/// extraction `get_object_details` does for a single object, but across every cached type.
+#[tauri::command]
+pub fn generate_sdk(state: State<'_, AppState>) -> Result<Vec<GeneratedHeaderInfo>, String> {
+    let process_state = state.process.lock().unwrap();
+    let process = process_state.as_ref().ok_or("No process attached")?;
+
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let offsets = state.active_offsets();
+
+    let headers = crate::backend::unreal::sdk_export::generate_sdk(&state.object_manager, process, &name_pool, &offsets);
+    println!("[generate_sdk] Generated {} header(s)", headers.len());
+
+    Ok(headers.into_iter().map(|h| GeneratedHeaderInfo { package_name: h.package_name, file_name: h.file_name, content: h.content }).collect())
+}
+
+/// Caps how many times `write_and_verify` re-attempts a write whose read-back doesn't match —
+/// a live game's own thread can clobber our write between the write and the verification read,
+/// so a single write-then-trust isn't reliable, but an unbounded retry could spin forever against
+/// a field the game writes every frame.
+const MAX_WRITE_ATTEMPTS: u32 = 3;
+
+/// Writes `new_value` through `write_property_value`, then re-reads the same address with
+/// `read_back_value` to confirm it actually stuck, retrying up to `MAX_WRITE_ATTEMPTS` times on a
+/// mismatch. Returns the confirmed `live_value` on success, or an error naming the last read-back
+/// it saw if the write never held.
+fn write_and_verify(proc: &Process, name_pool: &crate::backend::unreal::name_pool::FNamePool, address: usize, property_type: &str, bit_mask: u8, new_value: &str) -> Result<String, String> {
+    // Compare against `new_value` re-formatted through the same parse table `read_back_value`
+    // decodes with (e.g. `"1.0"` -> `"1"`), not the raw input string — otherwise a successful
+    // write whose input wasn't already in canonical form reads as a mismatch.
+    let expected = crate::backend::unreal::property_write::normalize_value_for_comparison(property_type, new_value)?;
+    let mut last_seen = String::new();
+
+    for attempt in 1..=MAX_WRITE_ATTEMPTS {
+        crate::backend::unreal::property_write::write_property_value(proc, name_pool, address, property_type, bit_mask, new_value)?;
+
+        last_seen = crate::backend::unreal::property_write::read_back_value(proc, name_pool, address, property_type, bit_mask);
+        if last_seen == expected {
+            return Ok(last_seen);
+        }
+
+        println!("[set_instance_property] write to 0x{:X} didn't stick on attempt {}/{} (wrote '{}', read '{}')", address, attempt, MAX_WRITE_ATTEMPTS, new_value, last_seen);
+    }
+
+    Err(format!("Write to 0x{:X} did not stick after {} attempts (wrote '{}', last read back '{}')", address, MAX_WRITE_ATTEMPTS, new_value, last_seen))
+}
+
+/// Parses `new_value` according to `property_type` (same decode table `read_member_value` reads
+/// with), writes it into the attached process's memory, and verifies the write stuck via
+/// `write_and_verify` before returning the confirmed `live_value` to the UI. One-shot — use
+/// `freeze_property` instead if the value needs to keep re-applying against the target's own writes.
+#[tauri::command]
+pub fn set_instance_property(state: State<'_, AppState>, memory_address: String, property_type: String, bit_mask: u8, new_value: String) -> Result<String, String> {
+    let address = usize::from_str_radix(memory_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid memory address")?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
+    let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
+
+    write_and_verify(proc, name_pool, address, &property_type, bit_mask, &new_value)
+}
+
+/// Sibling of `set_instance_property` for a single `TArray` element's `memory_address` (as
+/// reported by `get_instance_details`'s array children) — same decode table, same write-verify
+/// retry loop, kept as its own command since an element write has no class-member `offset` to
+/// resolve from and may grow into element-specific handling (e.g. growing the backing array)
+/// that `set_instance_property` shouldn't carry.
+#[tauri::command]
+pub fn set_array_element(state: State<'_, AppState>, memory_address: String, property_type: String, bit_mask: u8, new_value: String) -> Result<String, String> {
+    let address = usize::from_str_radix(memory_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid memory address")?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let proc = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let pool_guard = state.name_pool.lock().map_err(|_| "Lock failed")?;
+    let name_pool = pool_guard.as_ref().ok_or("Name pool not initialized")?;
+
+    write_and_verify(proc, name_pool, address, &property_type, bit_mask, &new_value)
+}
+
+/// Registers `(address, property_type, value)` so the background freeze loop keeps re-writing it
+/// every tick, lazily spawning that loop on the first call since there's no `.setup()` hook in
+/// this app. Emits `frozen-properties-changed` with the full address list so the UI can render
+/// freeze indicators without polling.
+#[tauri::command]
+pub fn freeze_property(app_handle: tauri::AppHandle, state: State<'_, AppState>, memory_address: String, property_type: String, bit_mask: u8, value: String) -> Result<(), String> {
+    let address = usize::from_str_radix(memory_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid memory address")?;
+
+    state.frozen_properties.insert(address, crate::backend::unreal::property_write::FrozenProperty { property_type, bit_mask, value });
+
+    if state.freeze_loop_started.compare_exchange(false, true, std::sync::atomic::Ordering::SeqCst, std::sync::atomic::Ordering::SeqCst).is_ok() {
+        spawn_freeze_loop(app_handle.clone());
+    }
+
+    emit_frozen_properties(&app_handle, &state);
+    Ok(())
+}
+
+/// Removes a previously frozen address; the background loop simply stops touching it.
+#[tauri::command]
+pub fn unfreeze_property(app_handle: tauri::AppHandle, state: State<'_, AppState>, memory_address: String) -> Result<(), String> {
+    let address = usize::from_str_radix(memory_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid memory address")?;
+
+    state.frozen_properties.remove(&address);
+    emit_frozen_properties(&app_handle, &state);
+    Ok(())
+}
+
+#[derive(Clone, serde::Serialize)]
+struct FrozenPropertiesPayload {
+    addresses: Vec<String>,
+}
+
+fn emit_frozen_properties(app_handle: &tauri::AppHandle, state: &State<'_, AppState>) {
+    let addresses = state.frozen_properties.iter().map(|e| format!("0x{:X}", e.key())).collect();
+    app_handle.emit("frozen-properties-changed", FrozenPropertiesPayload { addresses }).ok();
+}
+
+/// The one background task in the whole app that doesn't run to completion: wakes every 30ms
+/// and re-applies every entry in `frozen_properties` against the attached process, so a frozen
+/// value keeps overriding whatever the target process's own code writes to it. Started lazily by
+/// the first `freeze_property` call and lives for the rest of the app's lifetime.
+fn spawn_freeze_loop(app_handle: tauri::AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_millis(30));
+
+        let state = app_handle.state::<AppState>();
+        if state.frozen_properties.is_empty() {
+            continue;
+        }
+
+        let process_lock = state.process.lock().unwrap();
+        let pool_guard = state.name_pool.lock().unwrap();
+        if let (Some(proc), Some(name_pool)) = (process_lock.as_ref(), pool_guard.as_ref()) {
+            for entry in state.frozen_properties.iter() {
+                let address = *entry.key();
+                let frozen = entry.value();
+                let _ = crate::backend::unreal::property_write::write_property_value(proc, name_pool, address, &frozen.property_type, frozen.bit_mask, &frozen.value);
+            }
+        }
+    });
+}
+
+/// Writes a full SDK export (C++ headers, Rust modules, or JSON, per `format`) to `out_dir`, reusing the same
+/// property-walk and inheritance data `get_instance_details`/`add_inspector` already expose in
+/// the UI. Runs on `spawn_blocking` since it walks the entire object cache, and streams progress
+/// through the `sdk-export-progress` event so the frontend can show a progress bar.
+#[tauri::command]
+pub async fn export_sdk(app_handle: tauri::AppHandle, state: State<'_, AppState>, format: String, out_dir: String) -> Result<usize, String> {
+    let process = state.process.lock().unwrap().clone().ok_or("No process attached")?;
+    let name_pool = {
+        let np_lock = state.name_pool.lock().unwrap();
+        np_lock.as_ref().ok_or("FNamePool not yet parsed. Please parse GUObjectArray first.")?.clone()
+    };
+    let obj_mgr = Arc::clone(&state.object_manager);
+    let offsets = state.active_offsets();
+    let format: crate::backend::unreal::sdk_export::SdkExportFormat = format.parse().unwrap();
+    let out_dir = std::path::PathBuf::from(out_dir);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let count = crate::backend::unreal::sdk_export::export_sdk_to_disk(&obj_mgr, &process, &name_pool, &offsets, format, &out_dir, &app_handle)?;
+        println!("[export_sdk] Wrote {} file(s) to {:?}", count, out_dir);
+        Ok(count)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Parses the `value_type`/`comparator` string pair the frontend sends (mirroring how
+/// `property_type` strings are parsed elsewhere in this file) into a `ValueScan` filter, decoding
+/// `operand` against `value_type` only when `comparator` is `"Exact"`.
+fn parse_scan_filter(value_type: &str, comparator: &str, operand: Option<f64>) -> Result<(crate::backend::os::value_scan::ValueType, crate::backend::os::value_scan::Comparator), String> {
+    use crate::backend::os::value_scan::{Comparator, ScanValue, ValueType};
+
+    let value_type = match value_type {
+        "I32" => ValueType::I32,
+        "I64" => ValueType::I64,
+        "F32" => ValueType::F32,
+        "F64" => ValueType::F64,
+        other => return Err(format!("Unknown value type '{}'", other)),
+    };
+
+    let comparator = match comparator {
+        "Unknown" => Comparator::Unknown,
+        "Changed" => Comparator::Changed,
+        "Unchanged" => Comparator::Unchanged,
+        "Increased" => Comparator::Increased,
+        "Decreased" => Comparator::Decreased,
+        "Exact" => {
+            let operand = operand.ok_or("Exact comparator requires an operand")?;
+            let value = match value_type {
+                ValueType::I32 => ScanValue::I32(operand as i32),
+                ValueType::I64 => ScanValue::I64(operand as i64),
+                ValueType::F32 => ScanValue::F32(operand as f32),
+                ValueType::F64 => ScanValue::F64(operand),
+            };
+            Comparator::ExactValue(value)
+        }
+        other => return Err(format!("Unknown comparator '{}'", other)),
+    };
+
+    Ok((value_type, comparator))
+}
+
+/// Starts a fresh `ValueScan` session over `[start_address, end_address)`, replacing whatever
+/// session was previously stored, and returns how many candidates matched `comparator`.
+#[tauri::command]
+pub fn value_scan_first(state: State<'_, AppState>, start_address: String, end_address: String, value_type: String, comparator: String, operand: Option<f64>) -> Result<usize, String> {
+    let start_address = usize::from_str_radix(start_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid start address")?;
+    let end_address = usize::from_str_radix(end_address.trim_start_matches("0x"), 16).map_err(|_| "Invalid end address")?;
+    let (value_type, filter) = parse_scan_filter(&value_type, &comparator, operand)?;
+
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let process = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let session = crate::backend::os::value_scan::ValueScan::first_scan(&process.memory, start_address, end_address, value_type, filter);
+    let result_count = session.result_count();
+
+    *state.value_scan_session.lock().map_err(|_| "Lock failed")? = Some(session);
+    Ok(result_count)
+}
+
+/// Narrows the in-progress `ValueScan` session by `comparator`, re-reading only the addresses it
+/// already retained. Errors if `value_scan_first` hasn't been called yet.
+#[tauri::command]
+pub fn value_scan_next(state: State<'_, AppState>, comparator: String, operand: Option<f64>) -> Result<usize, String> {
+    let process_lock = state.process.lock().map_err(|_| "Lock failed")?;
+    let process = process_lock.as_ref().ok_or("Process not attached")?;
+
+    let mut session_lock = state.value_scan_session.lock().map_err(|_| "Lock failed")?;
+    let session = session_lock.as_ref().ok_or("No value-scan session in progress; call value_scan_first first")?;
+
+    let (_, filter) = parse_scan_filter(
+        match session.value_type() {
+            crate::backend::os::value_scan::ValueType::I32 => "I32",
+            crate::backend::os::value_scan::ValueType::I64 => "I64",
+            crate::backend::os::value_scan::ValueType::F32 => "F32",
+            crate::backend::os::value_scan::ValueType::F64 => "F64",
+        },
+        &comparator,
+        operand,
+    )?;
+
+    let next_session = crate::backend::os::value_scan::ValueScan::next_scan(&process.memory, session, filter);
+    let result_count = next_session.result_count();
+    *session_lock = Some(next_session);
+    Ok(result_count)
+}
+
+#[derive(serde::Serialize)]
+pub struct ValueScanHit {
+    pub address: String,
+    pub value: crate::backend::os::value_scan::ScanValue,
+}
+
+#[derive(serde::Serialize)]
+pub struct PagedValueScanResults {
+    pub hits: Vec<ValueScanHit>,
+    pub total_count: usize,
+}
+
+/// Same windowing as `get_packages`/`get_objects`, over the current `ValueScan` session's
+/// retained candidates.
+#[tauri::command]
+pub fn get_value_scan_results(state: State<'_, AppState>, offset: usize, limit: usize) -> Result<PagedValueScanResults, String> {
+    let session_lock = state.value_scan_session.lock().map_err(|_| "Lock failed")?;
+    let session = session_lock.as_ref().ok_or("No value-scan session in progress")?;
+
+    let mut results = session.results();
+    let total_count = results.len();
+    let page = if offset >= total_count { Vec::new() } else { results.drain(offset..).take(limit).collect::<Vec<_>>() };
+
+    Ok(PagedValueScanResults {
+        hits: page.into_iter().map(|(address, value)| ValueScanHit { address: format!("0x{:X}", address), value }).collect(),
+        total_count,
+    })
+}
+
 pub fn get_handlers() -> impl Fn(tauri::ipc::Invoke) -> bool {
     tauri::generate_handler![
         fetch_system_processes,
         attach_to_process,
         get_ue_version,
+        get_process_details,
         get_fname_pool_address,
         parse_fname_pool,
         parse_guobject_array,
+        cancel_parse,
+        pause_parse,
+        resume_parse,
         get_guobject_array_address,
         get_gworld_address,
         show_base_address,
         get_packages,
         get_objects,
+        get_array_elements,
         get_object_details,
         global_search,
         search_object_instances,
         add_inspector,
-        get_instance_details
+        get_instance_details,
+        read_instance_members,
+        read_instance_values,
+        export_class_graph,
+        export_object_graph,
+        build_object_reference_graph,
+        find_object_reference_path,
+        find_object_referencers,
+        rebuild_index,
+        invalidate_index,
+        cancel_instance_scan,
+        generate_sdk,
+        export_usmap,
+        resolve_offsets,
+        list_offset_profiles,
+        set_offset_profile,
+        save_offset_profile,
+        set_resolve_steps,
+        save_dump,
+        load_dump,
+        set_instance_property,
+        set_array_element,
+        freeze_property,
+        unfreeze_property,
+        export_sdk,
+        find_fnames_by_name,
+        find_objects_by_name,
+        export_symbols,
+        get_function_disassembly,
+        value_scan_first,
+        value_scan_next,
+        get_value_scan_results
     ]
 }
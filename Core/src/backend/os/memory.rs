@@ -1,10 +1,14 @@
 use std::ffi::c_void;
 use windows::Win32::Foundation::{CloseHandle, HANDLE};
-use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
+use windows::Win32::System::Diagnostics::Debug::{ReadProcessMemory, WriteProcessMemory};
 
 #[derive(Debug)]
 pub struct Memory {
     handle: HANDLE,
+    /// 8 for a native x64 target, 4 for an X86 process running under WOW64 — set once at attach
+    /// time from `Process::arch`/`Process::pointer_size` and consulted by `read_pointer`/
+    /// `try_read_pointer` so scanning/pointer-chasing code doesn't have to hardcode `u64`.
+    pointer_size: usize,
 }
 
 // Win32 process handles used for memory reading are thread-safe and can be shared across threads.
@@ -12,8 +16,8 @@ unsafe impl Send for Memory {}
 unsafe impl Sync for Memory {}
 
 impl Memory {
-    pub fn new(handle: HANDLE) -> Self {
-        Self { handle }
+    pub fn new(handle: HANDLE, pointer_size: usize) -> Self {
+        Self { handle, pointer_size }
     }
 
     /// Exposes the inner OS handle for specific Win32 API calls like VirtualQueryEx
@@ -21,6 +25,13 @@ impl Memory {
         self.handle
     }
 
+    /// The pointer width this `Memory` was constructed with — 8 on a native x64 target, 4 for an
+    /// X86 process running under WOW64. Lets arch-aware callers (e.g. `Process::get_details`'s
+    /// `UNICODE_STRING` decoding) pick the right field layout without re-deriving it from `Process`.
+    pub fn pointer_size(&self) -> usize {
+        self.pointer_size
+    }
+
     /// Read raw bytes from the process memory
     pub fn read_bytes(&self, address: usize, size: usize) -> Result<Vec<u8>, String> {
         let mut buffer = vec![0u8; size];
@@ -57,15 +68,24 @@ impl Memory {
         }
     }
 
-    /// Read a pointer address (64-bit)
+    /// Read a pointer-sized value, honoring `pointer_size` (8 bytes on native x64, 4 bytes for an
+    /// X86 process running under WOW64).
     pub fn read_pointer(&self, address: usize) -> Result<usize, String> {
-        self.read::<u64>(address).map(|v| v as usize)
+        if self.pointer_size == 4 {
+            self.read::<u32>(address).map(|v| v as usize)
+        } else {
+            self.read::<u64>(address).map(|v| v as usize)
+        }
     }
 
     /// Fast-path read pointer: returns Option, zero allocation on failure.
     #[inline]
     pub fn try_read_pointer(&self, address: usize) -> Option<usize> {
-        self.try_read::<u64>(address).map(|v| v as usize)
+        if self.pointer_size == 4 {
+            self.try_read::<u32>(address).map(|v| v as usize)
+        } else {
+            self.try_read::<u64>(address).map(|v| v as usize)
+        }
     }
 
     /// Get the size of the memory region at the given address using VirtualQueryEx
@@ -82,6 +102,26 @@ impl Memory {
         }
     }
 
+    /// Write raw bytes into process memory
+    pub fn write_bytes(&self, address: usize, data: &[u8]) -> Result<(), String> {
+        let mut bytes_written = 0;
+
+        let success = unsafe { WriteProcessMemory(self.handle, address as *const c_void, data.as_ptr() as *const c_void, data.len(), Some(&mut bytes_written)) };
+
+        if success.is_ok() && bytes_written == data.len() {
+            Ok(())
+        } else {
+            Err(format!("Failed to write memory at 0x{:X}", address))
+        }
+    }
+
+    /// Write a specific type's raw bytes into process memory
+    pub fn write<T: Copy>(&self, address: usize, value: T) -> Result<(), String> {
+        let size = std::mem::size_of::<T>();
+        let bytes = unsafe { std::slice::from_raw_parts(&value as *const T as *const u8, size) };
+        self.write_bytes(address, bytes)
+    }
+
     /// Read a null-terminated UTF-8 string or ASCII string
     pub fn read_string(&self, address: usize, max_length: usize) -> Result<String, String> {
         let mut result = String::new();
@@ -108,6 +148,25 @@ impl Memory {
 
         Ok(result)
     }
+
+    /// Read a null-terminated UTF-16LE string — same shape as `read_string`, but for engine
+    /// builds whose `FNamePool` stores wide characters (`wide_strings` on the active offset
+    /// profile) instead of ANSI/UTF-8.
+    pub fn read_wide_string(&self, address: usize, max_length: usize) -> Result<String, String> {
+        let mut units = Vec::with_capacity(max_length);
+        let mut current_addr = address;
+
+        for _ in 0..max_length {
+            let unit = self.read::<u16>(current_addr)?;
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+            current_addr += 2;
+        }
+
+        Ok(String::from_utf16_lossy(&units))
+    }
 }
 
 impl Drop for Memory {
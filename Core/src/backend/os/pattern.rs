@@ -0,0 +1,163 @@
+/// One compiled signature position. `Exact`/`Masked` are the common cases and also get mirrored
+/// into `Pattern`'s parallel `bytes`/`mask` arrays (mask bits that matter must agree); `Range` and
+/// `Alt` cannot be expressed as a single byte+mask and are only checked through this enum.
+#[derive(Clone, Debug, PartialEq)]
+enum ByteToken {
+    /// A fully fixed byte, e.g. `"4C"`.
+    Exact(u8),
+    /// A byte with some bits masked out, e.g. `"?"`/`"??"` (mask `0x00`), `"4?"` (mask `0xF0`),
+    /// `"?C"` (mask `0x0F`). `buffer_byte & mask == value & mask` is the match rule.
+    Masked(u8, u8),
+    /// An inclusive byte-value range, e.g. `"[30-3F]"`.
+    Range(u8, u8),
+    /// One of a small fixed set of byte values, e.g. `"(74|75)"`.
+    Alt(Vec<u8>),
+}
+
+/// A signature compiled once from a string like `"4C 8D 05 ? ? ? ? EB 16"`, instead of being
+/// re-parsed into a fresh `Vec<Option<u8>>` on every scan (the approach `Scanner::parse_signature`
+/// still uses for its simpler whole-byte-wildcard call sites). Adds richer per-position tokens —
+/// nibble wildcards, byte ranges, and alternations — on top of the same idea.
+///
+/// `bytes`/`mask` are the parallel arrays the common `Exact`/`Masked` positions compile to (a
+/// `Range`/`Alt` position has `mask = 0x00` there and is matched through `tokens` instead, the
+/// same way a whole-byte wildcard already does); `anchor_index` records the first fully-fixed
+/// (`Exact`) byte so scanning can fast-skip to candidate positions where that byte occurs.
+pub struct Pattern {
+    tokens: Vec<ByteToken>,
+    bytes: Vec<u8>,
+    mask: Vec<u8>,
+    anchor_index: Option<usize>,
+}
+
+impl Pattern {
+    /// Compiles a whitespace-separated signature string into a `Pattern`. Each token is one of:
+    /// a hex byte (`"4C"`), a whole-byte wildcard (`"?"`/`"??"`), a nibble wildcard (`"4?"`/
+    /// `"?C"`), a byte range (`"[30-3F]"`), or an alternation (`"(74|75)"`).
+    pub fn parse(signature: &str) -> Result<Pattern, String> {
+        let tokens: Vec<ByteToken> = signature.split_whitespace().map(Self::parse_token).collect::<Result<_, _>>()?;
+        if tokens.is_empty() {
+            return Err("Empty signature".to_string());
+        }
+
+        let bytes: Vec<u8> = tokens
+            .iter()
+            .map(|t| match t {
+                ByteToken::Exact(v) => *v,
+                ByteToken::Masked(v, _) => *v,
+                ByteToken::Range(_, _) | ByteToken::Alt(_) => 0,
+            })
+            .collect();
+        let mask: Vec<u8> = tokens
+            .iter()
+            .map(|t| match t {
+                ByteToken::Exact(_) => 0xFF,
+                ByteToken::Masked(_, m) => *m,
+                ByteToken::Range(_, _) | ByteToken::Alt(_) => 0x00,
+            })
+            .collect();
+        let anchor_index = tokens.iter().position(|t| matches!(t, ByteToken::Exact(_)));
+
+        Ok(Pattern { tokens, bytes, mask, anchor_index })
+    }
+
+    fn parse_token(raw: &str) -> Result<ByteToken, String> {
+        if raw == "?" || raw == "??" {
+            return Ok(ByteToken::Masked(0, 0x00));
+        }
+
+        if let Some(inner) = raw.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let (lo, hi) = inner.split_once('-').ok_or_else(|| format!("Invalid range token '{}': expected [XX-YY]", raw))?;
+            let lo = u8::from_str_radix(lo, 16).map_err(|_| format!("Invalid range token '{}'", raw))?;
+            let hi = u8::from_str_radix(hi, 16).map_err(|_| format!("Invalid range token '{}'", raw))?;
+            return Ok(ByteToken::Range(lo, hi));
+        }
+
+        if let Some(inner) = raw.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            let values: Vec<u8> = inner.split('|').map(|v| u8::from_str_radix(v, 16).map_err(|_| format!("Invalid alternation token '{}'", raw))).collect::<Result<_, _>>()?;
+            return Ok(ByteToken::Alt(values));
+        }
+
+        if raw.len() == 2 {
+            let chars: Vec<char> = raw.chars().collect();
+            let (hi_wild, lo_wild) = (chars[0] == '?', chars[1] == '?');
+            if hi_wild && lo_wild {
+                return Ok(ByteToken::Masked(0, 0x00));
+            }
+            if hi_wild {
+                let lo_val = chars[1].to_digit(16).ok_or_else(|| format!("Invalid nibble token '{}'", raw))? as u8;
+                return Ok(ByteToken::Masked(lo_val, 0x0F));
+            }
+            if lo_wild {
+                let hi_val = chars[0].to_digit(16).ok_or_else(|| format!("Invalid nibble token '{}'", raw))? as u8;
+                return Ok(ByteToken::Masked(hi_val << 4, 0xF0));
+            }
+            let v = u8::from_str_radix(raw, 16).map_err(|_| format!("Invalid byte token '{}'", raw))?;
+            return Ok(ByteToken::Exact(v));
+        }
+
+        Err(format!("Unrecognized signature token '{}'", raw))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    fn matches_at(&self, buffer: &[u8], pos: usize) -> bool {
+        for (j, token) in self.tokens.iter().enumerate() {
+            let b = buffer[pos + j];
+            let ok = match token {
+                ByteToken::Exact(v) => b == *v,
+                ByteToken::Masked(v, m) => (b & m) == (v & m),
+                ByteToken::Range(lo, hi) => b >= *lo && b <= *hi,
+                ByteToken::Alt(values) => values.contains(&b),
+            };
+            if !ok {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Search for this pattern within a buffer, over the richer token set above. When the pattern
+    /// has a fully-fixed byte (`anchor_index`), candidate positions are fast-skipped to the next
+    /// occurrence of that byte at the matching offset instead of testing every position.
+    pub fn find_in_buffer(&self, buffer: &[u8]) -> Vec<usize> {
+        let mut matches = Vec::new();
+        let len = self.tokens.len();
+        if len == 0 || buffer.len() < len {
+            return matches;
+        }
+
+        let end = buffer.len() - len;
+        let mut i = 0;
+
+        while i <= end {
+            if let Some(anchor_idx) = self.anchor_index {
+                let anchor_byte = self.bytes[anchor_idx];
+                let mut found = false;
+                while i <= end {
+                    if buffer[i + anchor_idx] == anchor_byte {
+                        found = true;
+                        break;
+                    }
+                    i += 1;
+                }
+                if !found {
+                    break;
+                }
+            }
+
+            if self.matches_at(buffer, i) {
+                matches.push(i);
+            }
+            i += 1;
+        }
+
+        matches
+    }
+}
@@ -2,9 +2,9 @@ use crate::backend::os::memory::Memory;
 use crate::backend::state::AppState;
 use std::collections::HashSet;
 use sysinfo::System;
-use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
-use windows::Win32::System::Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, Module32First, MODULEENTRY32, TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32};
-use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+use windows::Win32::Foundation::{BOOL, HANDLE, HWND, LPARAM};
+use windows::Win32::System::Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, Module32First, Module32Next, MODULEENTRY32, TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32};
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE};
 use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, GetWindowTextLengthW, GetWindowThreadProcessId, IsWindowVisible};
 
 #[derive(Debug, Clone)]
@@ -15,6 +15,18 @@ pub struct Process {
     pub memory: Memory,
     pub main_module_base: usize,
     pub main_module_size: usize,
+    /// Detected at `attach` time via `ProcessWow64Information` — a UE title can be native 64-bit
+    /// or running 32-bit under WOW64, and pointer-chasing/scanning needs to know which.
+    pub arch: ProcessArch,
+}
+
+/// A process's bitness, detected once at `attach` time. Signature results and pointer resolution
+/// are only correct when scanning/reading code agrees with this: a WOW64 (32-bit) target stores
+/// pointers as 4 bytes, not 8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum ProcessArch {
+    X64,
+    X86,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -23,10 +35,36 @@ pub struct ProcessInfo {
     pub name: String,
 }
 
+/// One module loaded in the attached process, as enumerated at attach time — the base/size/name
+/// `AppState::resolve_rva` walks to turn an absolute address back into `"ModuleName.dll+0x1234"`.
+#[derive(Debug, Clone)]
+pub struct ModuleInfo {
+    pub name: String,
+    pub base: usize,
+    pub size: usize,
+}
+
+/// Process metadata read straight out of the target's PEB — the same command line, working
+/// directory, parent PID, start time, and environment the `sysinfo` Windows backend extracts,
+/// fetched directly via `Process::get_details` so the attach panel can act as a real process
+/// inspector instead of only showing `pid`/`name`.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ProcessDetails {
+    pub command_line: String,
+    pub current_directory: String,
+    pub parent_pid: u32,
+    /// Raw `FILETIME` ticks (100ns intervals since 1601-01-01) from `GetProcessTimes` — left
+    /// unconverted since the caller may want any of several human-readable formats.
+    pub start_time: u64,
+    pub environment: Vec<(String, String)>,
+}
+
 impl Process {
     /// Open/Attach to a process by its PID, creating a Memory reader for it and storing it in State
     pub fn attach(state: &tauri::State<'_, AppState>, pid: u32, name: &str) -> Result<String, String> {
-        let handle = unsafe { OpenProcess(PROCESS_VM_READ | PROCESS_QUERY_INFORMATION, false, pid) }.map_err(|e| format!("Failed to open process PID {}: {}", pid, e))?;
+        // VM_WRITE/VM_OPERATION are needed for `set_instance_property`/the freeze subsystem on
+        // top of the original read-only access.
+        let handle = unsafe { OpenProcess(PROCESS_VM_READ | PROCESS_VM_WRITE | PROCESS_VM_OPERATION | PROCESS_QUERY_INFORMATION, false, pid) }.map_err(|e| format!("Failed to open process PID {}: {}", pid, e))?;
 
         if handle.is_invalid() {
             return Err(format!("Invalid handle for PID {}", pid));
@@ -37,11 +75,22 @@ impl Process {
         let exe_path = sys.process(sysinfo::Pid::from_u32(pid)).and_then(|p| p.exe()).map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
 
         let (main_module_base, main_module_size) = Self::get_main_module_info(pid)?;
+        let arch = match Self::query_wow64_peb(handle) {
+            Ok(Some(_)) => ProcessArch::X86,
+            _ => ProcessArch::X64,
+        };
+        let pointer_size = match arch {
+            ProcessArch::X64 => 8,
+            ProcessArch::X86 => 4,
+        };
 
-        let process = Self { pid, name: name.to_string(), exe_path, memory: Memory::new(handle), main_module_base, main_module_size };
+        let process = Self { pid, name: name.to_string(), exe_path, memory: Memory::new(handle, pointer_size), main_module_base, main_module_size, arch };
 
         let mut process_state = state.process.lock().unwrap();
         *process_state = Some(process);
+        drop(process_state);
+
+        *state.modules.lock().unwrap() = Self::enumerate_modules(pid).unwrap_or_default();
 
         Ok(format!("Successfully attached to {}", name))
     }
@@ -109,6 +158,71 @@ impl Process {
         }
     }
 
+    /// Enumerates every module (EXE + DLLs) currently loaded in `pid`, for `AppState::resolve_rva`
+    /// to search — the same `Module32First`/`Next` snapshot walk `get_main_module_info` does for
+    /// just the first entry, extended to the full module list.
+    fn enumerate_modules(pid: u32) -> Result<Vec<ModuleInfo>, String> {
+        unsafe {
+            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, pid).map_err(|e| format!("Failed to create toolhelp snapshot: {}", e))?;
+
+            if snapshot.is_invalid() {
+                return Err("Invalid handle for toolhelp snapshot".to_string());
+            }
+
+            let mut modules = Vec::new();
+            let mut module_entry = MODULEENTRY32 { dwSize: std::mem::size_of::<MODULEENTRY32>() as u32, ..Default::default() };
+
+            if Module32First(snapshot, &mut module_entry).is_ok() {
+                loop {
+                    let name_bytes: Vec<u8> = module_entry.szModule.iter().take_while(|&&b| b != 0).copied().collect();
+                    let name = String::from_utf8_lossy(&name_bytes).to_string();
+                    modules.push(ModuleInfo { name, base: module_entry.modBaseAddr as usize, size: module_entry.modBaseSize as usize });
+
+                    if Module32Next(snapshot, &mut module_entry).is_err() {
+                        break;
+                    }
+                }
+            }
+
+            windows::Win32::Foundation::CloseHandle(snapshot).ok();
+
+            Ok(modules)
+        }
+    }
+
+    /// Re-enumerates every module (EXE + DLLs) currently loaded in this process, for a caller
+    /// like `Scanner::scan_module` that wants to restrict a scan to one specific DLL instead of
+    /// the whole address space. Unlike `AppState::modules` (snapshotted once at attach time),
+    /// this re-walks the toolhelp snapshot fresh on every call.
+    pub fn get_modules(&self) -> Vec<ModuleInfo> {
+        Self::enumerate_modules(self.pid).unwrap_or_default()
+    }
+
+    /// The pointer width scanning/pointer-chasing code should use for this process: 8 bytes for
+    /// a native 64-bit target, 4 for a 32-bit target running under WOW64. Delegates to
+    /// `Memory::pointer_size`, which `read_pointer`/`try_read_pointer` already consult on every
+    /// pointer-sized read, so callers reaching for this get the same width those use.
+    pub fn pointer_size(&self) -> usize {
+        self.memory.pointer_size()
+    }
+
+    /// Queries `ProcessWow64Information` (class 26) for `handle`: `Ok(Some(peb32_base))` when the
+    /// target is a 32-bit process running under WOW64 (the call returns its 32-bit PEB address),
+    /// `Ok(None)` for a native 64-bit process.
+    fn query_wow64_peb(handle: HANDLE) -> Result<Option<usize>, String> {
+        use std::ffi::c_void;
+        use windows::Win32::System::Threading::{NtQueryInformationProcess, PROCESSINFOCLASS};
+
+        let mut peb32: usize = 0;
+        let mut return_length = 0u32;
+        let status = unsafe { NtQueryInformationProcess(handle, PROCESSINFOCLASS(26), &mut peb32 as *mut _ as *mut c_void, std::mem::size_of::<usize>() as u32, &mut return_length) };
+        if status.is_err() {
+            return Err(format!("NtQueryInformationProcess(ProcessWow64Information) failed: {:?}", status));
+        }
+
+        Ok(if peb32 == 0 { None } else { Some(peb32) })
+    }
+
     /// Fetches the Unreal Engine version by reading the VS_FIXEDFILEINFO of the executable.
     pub fn get_ue_version(&self) -> Result<String, String> {
         if self.exe_path.is_empty() {
@@ -155,4 +269,102 @@ impl Process {
             Ok(format!("{}.{}.{}.{}", major, minor, build, revision))
         }
     }
+
+    /// Reads the target's PEB to surface the process metadata `sysinfo` can't see into: its
+    /// command line, current working directory, parent PID, start time, and environment block.
+    /// Calls `NtQueryInformationProcess(ProcessBasicInformation)` for `PebBaseAddress`, then
+    /// follows `PEB.ProcessParameters` to an `RTL_USER_PROCESS_PARAMETERS` and decodes the
+    /// `CommandLine`/`CurrentDirectory` `UNICODE_STRING`s and the environment block (a
+    /// double-NUL-terminated run of UTF-16 `KEY=VALUE` entries) via `Memory::read_wide_string`.
+    pub fn get_details(&self) -> Result<ProcessDetails, String> {
+        use windows::Win32::Foundation::FILETIME;
+        use windows::Win32::System::Threading::{GetProcessTimes, NtQueryInformationProcess, PROCESSINFOCLASS, PROCESS_BASIC_INFORMATION};
+        use std::ffi::c_void;
+
+        // ProcessBasicInformation (class 0) gives us both the PEB address and the parent PID in
+        // one call — the same undocumented-but-stable NTAPI every process inspector relies on.
+        let mut pbi = PROCESS_BASIC_INFORMATION::default();
+        let mut return_length = 0u32;
+        let status = unsafe { NtQueryInformationProcess(self.memory.handle(), PROCESSINFOCLASS(0), &mut pbi as *mut _ as *mut c_void, std::mem::size_of::<PROCESS_BASIC_INFORMATION>() as u32, &mut return_length) };
+        if status.is_err() {
+            return Err(format!("NtQueryInformationProcess(ProcessBasicInformation) failed: {:?}", status));
+        }
+
+        // A WOW64 target's `PebBaseAddress` here points at its native 64-bit PEB, which the
+        // 32-bit process itself never touches — its actual `ProcessParameters` hang off the
+        // PEB32 `query_wow64_peb` returns, laid out with 4-byte pointers instead of 8 (which
+        // `self.memory.read_pointer` already accounts for via `Process::pointer_size`).
+        let process_parameters = match self.arch {
+            ProcessArch::X64 => {
+                let peb_base = pbi.PebBaseAddress as usize;
+                self.memory.read_pointer(peb_base + 0x20)?
+            }
+            ProcessArch::X86 => {
+                let peb32_base = Self::query_wow64_peb(self.memory.handle())?.ok_or("Process reports X86 arch but has no WOW64 PEB")?;
+                self.memory.read_pointer(peb32_base + 0x10)?
+            }
+        };
+
+        // Offsets below are `RTL_USER_PROCESS_PARAMETERS`'s well-known layout and that of the
+        // 32-bit `RTL_USER_PROCESS_PARAMETERS32` variant (4-byte pointers and handles, so its
+        // offsets are not simply half of the 64-bit ones).
+        let (current_directory_offset, command_line_offset, environment_offset) = match self.arch {
+            ProcessArch::X64 => (0x38, 0x70, 0x80),
+            ProcessArch::X86 => (0x24, 0x40, 0x48),
+        };
+
+        let command_line = Self::read_unicode_string(&self.memory, process_parameters + command_line_offset).unwrap_or_default();
+        let current_directory = Self::read_unicode_string(&self.memory, process_parameters + current_directory_offset).unwrap_or_default();
+        let environment_ptr = self.memory.read_pointer(process_parameters + environment_offset)?;
+        let environment = Self::read_environment_block(&self.memory, environment_ptr);
+
+        let mut creation = FILETIME::default();
+        let mut exit = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        unsafe { GetProcessTimes(self.memory.handle(), &mut creation, &mut exit, &mut kernel, &mut user) }.map_err(|e| format!("GetProcessTimes failed: {}", e))?;
+        let start_time = ((creation.dwHighDateTime as u64) << 32) | creation.dwLowDateTime as u64;
+
+        Ok(ProcessDetails { command_line, current_directory, parent_pid: pbi.InheritedFromUniqueProcessId as u32, start_time, environment })
+    }
+
+    /// Reads a `UNICODE_STRING`/`UNICODE_STRING32` (`Length: u16` at `address`, `Buffer` pointer
+    /// right after the `Length`/`MaximumLength` pair — padded to offset 8 on x64, unpadded at
+    /// offset 4 on x86) and decodes its UTF-16LE contents via `Memory::read_wide_string`.
+    fn read_unicode_string(memory: &Memory, address: usize) -> Result<String, String> {
+        let length: u16 = memory.read(address)?;
+        if length == 0 {
+            return Ok(String::new());
+        }
+
+        let buffer_offset = if memory.pointer_size() == 8 { 8 } else { 4 };
+        let buffer_ptr = memory.read_pointer(address + buffer_offset)?;
+        if buffer_ptr == 0 {
+            return Ok(String::new());
+        }
+
+        memory.read_wide_string(buffer_ptr, (length / 2) as usize)
+    }
+
+    /// Decodes a process environment block: consecutive NUL-terminated UTF-16LE `"KEY=VALUE"`
+    /// strings, ending at the first empty one (the block's closing double-NUL).
+    fn read_environment_block(memory: &Memory, address: usize) -> Vec<(String, String)> {
+        const MAX_ENTRIES: usize = 4096;
+        let mut entries = Vec::new();
+        let mut addr = address;
+
+        while entries.len() < MAX_ENTRIES {
+            let Ok(entry) = memory.read_wide_string(addr, 32 * 1024) else { break };
+            if entry.is_empty() {
+                break;
+            }
+
+            addr += (entry.encode_utf16().count() + 1) * 2;
+            if let Some((key, value)) = entry.split_once('=') {
+                entries.push((key.to_string(), value.to_string()));
+            }
+        }
+
+        entries
+    }
 }
@@ -1,8 +1,18 @@
 use crate::backend::os::memory::Memory;
+use crate::backend::os::pattern::Pattern;
+use crate::backend::os::process::ModuleInfo;
 use rayon::prelude::*;
 use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use windows::Win32::System::Memory::{VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_COMMIT, PAGE_GUARD, PAGE_NOACCESS};
 
+#[derive(Clone, serde::Serialize)]
+pub struct ScanProgressPayload {
+    pub regions_done: usize,
+    pub regions_total: usize,
+    pub hits_found: usize,
+}
+
 pub struct Scanner;
 
 impl Scanner {
@@ -11,75 +21,72 @@ impl Scanner {
         signature.split_whitespace().map(|s| if s == "?" || s == "??" { None } else { u8::from_str_radix(s, 16).ok() }).collect()
     }
 
-    /// Search for a byte pattern within a specific buffer. Highly optimized raw loop for max speed in both Dev and Release.
+    /// Search for a byte pattern within a specific buffer. Uses a wildcard-tolerant
+    /// Boyer-Moore-Horspool skip table built from the pattern's fixed trailing run, so a large
+    /// buffer scan can jump past several bytes per miss instead of re-checking every offset —
+    /// a big win on patterns whose first byte is common (the old first-byte fast-skip degraded
+    /// to O(n·m) there). Falls back to the naive stride-1 scan when the pattern's last byte is
+    /// itself a wildcard, since then there's no fixed tail to build a table from.
     pub fn find_pattern_in_buffer(buffer: &[u8], pattern: &[Option<u8>]) -> Vec<usize> {
         let mut matches = Vec::new();
-        if pattern.is_empty() || buffer.len() < pattern.len() {
+        let m = pattern.len();
+        if m == 0 || buffer.len() < m {
             return matches;
         }
 
-        let first_byte = pattern[0];
-        let mut i = 0;
-        let end = buffer.len() - pattern.len();
+        let end = buffer.len() - m;
+        let last_wildcard = pattern.iter().rposition(|b| b.is_none());
 
-        while i <= end {
-            // Fast skip finding the first byte
-            if let Some(b) = first_byte {
-                let mut found = false;
-                while i <= end {
-                    if buffer[i] == b {
-                        found = true;
-                        break;
-                    }
-                    i += 1;
-                }
-                if !found {
-                    break;
+        if last_wildcard == Some(m - 1) {
+            for i in 0..=end {
+                if Self::matches_pattern_at(buffer, pattern, i) {
+                    matches.push(i);
                 }
             }
+            return matches;
+        }
 
-            // Check the rest of the pattern
-            let mut matched = true;
-            for j in 1..pattern.len() {
-                if let Some(p) = pattern[j] {
-                    if buffer[i + j] != p {
-                        matched = false;
-                        break;
-                    }
-                }
+        // `w` is the index of the last wildcard (-1 if the pattern has none); everything after
+        // it, `pattern[w+1..]`, is fully fixed and is what the skip table is built from.
+        let w = last_wildcard.map(|idx| idx as isize).unwrap_or(-1);
+        let tail = (m as isize - 1 - w) as usize;
+        let last_byte = pattern[m - 1].expect("last_wildcard != m - 1, so the last pattern byte is fixed");
+
+        let mut shift = [tail; 256];
+        for i in (w + 1) as usize..m - 1 {
+            if let Some(b) = pattern[i] {
+                shift[b as usize] = (m - 1) - i;
             }
+        }
 
-            if matched {
+        let mut i = 0;
+        while i <= end {
+            let buffer_last = buffer[i + m - 1];
+            if buffer_last == last_byte && Self::matches_pattern_at(buffer, pattern, i) {
                 matches.push(i);
             }
-            i += 1;
+            i += shift[buffer_last as usize];
         }
 
         matches
     }
 
-    /// Scan a process's memory range for a given pattern
-    pub fn scan(memory: &Memory, start_address: usize, end_address: usize, signature: &str) -> Result<Vec<usize>, String> {
-        let pattern = Self::parse_signature(signature);
-        if pattern.is_empty() {
-            return Err("Invalid signature".to_string());
-        }
+    /// Checks whether `pattern` matches `buffer` at `pos`, treating `None` entries as wildcards.
+    fn matches_pattern_at(buffer: &[u8], pattern: &[Option<u8>], pos: usize) -> bool {
+        pattern.iter().enumerate().all(|(j, p)| p.map_or(true, |b| buffer[pos + j] == b))
+    }
 
+    /// Enumerate committed, readable memory regions in `[start_address, end_address)`.
+    /// `pub(crate)` so `ValueScan` can walk the same region list for its own numeric scans
+    /// instead of duplicating the `VirtualQueryEx` loop.
+    pub(crate) fn enumerate_regions(memory: &Memory, start_address: usize, end_address: usize) -> Vec<(usize, usize)> {
         let mut current_address = start_address;
         let mut regions: Vec<(usize, usize)> = Vec::new();
 
-        // Enumerate memory regions
         while current_address < end_address {
             let mut mem_info = MEMORY_BASIC_INFORMATION::default();
 
-            let result = unsafe {
-                VirtualQueryEx(
-                    memory.handle(), // We need to expose memory handle or let Memory do this
-                    Some(current_address as *const c_void),
-                    &mut mem_info,
-                    std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
-                )
-            };
+            let result = unsafe { VirtualQueryEx(memory.handle(), Some(current_address as *const c_void), &mut mem_info, std::mem::size_of::<MEMORY_BASIC_INFORMATION>()) };
 
             if result == 0 {
                 break;
@@ -95,6 +102,18 @@ impl Scanner {
             current_address += mem_info.RegionSize as usize;
         }
 
+        regions
+    }
+
+    /// Scan a process's memory range for a given pattern
+    pub fn scan(memory: &Memory, start_address: usize, end_address: usize, signature: &str) -> Result<Vec<usize>, String> {
+        let pattern = Self::parse_signature(signature);
+        if pattern.is_empty() {
+            return Err("Invalid signature".to_string());
+        }
+
+        let regions = Self::enumerate_regions(memory, start_address, end_address);
+
         // Search each valid region in parallel
         let results: Vec<usize> = regions
             .into_par_iter()
@@ -111,4 +130,142 @@ impl Scanner {
 
         Ok(results)
     }
+
+    /// Same as `scan`, but: (1) steps candidate positions by `alignment` bytes — correct for a
+    /// fixed-size aligned signature like an 8-byte pointer, and far faster than checking every
+    /// byte offset; (2) reports progress (regions scanned / hits found) through a Tauri event so
+    /// the UI can show a percentage; (3) is cooperatively cancellable via `cancel_flag`.
+    pub fn scan_aligned_cancellable(memory: &Memory, start_address: usize, end_address: usize, signature: &str, alignment: usize, app_handle: &tauri::AppHandle, event_name: &str, cancel_flag: &AtomicBool) -> Result<Vec<usize>, String> {
+        use tauri::Emitter;
+
+        let pattern = Self::parse_signature(signature);
+        if pattern.is_empty() {
+            return Err("Invalid signature".to_string());
+        }
+
+        let regions = Self::enumerate_regions(memory, start_address, end_address);
+        let regions_total = regions.len();
+        let regions_done = AtomicUsize::new(0);
+        let hits_found = AtomicUsize::new(0);
+
+        let results: Vec<usize> = regions
+            .into_par_iter()
+            .flat_map(|(base, size)| {
+                if cancel_flag.load(Ordering::Relaxed) {
+                    return Vec::new();
+                }
+
+                let hits = if let Ok(buffer) = memory.read_bytes(base, size) {
+                    Self::find_pattern_in_buffer_aligned(&buffer, &pattern, base, alignment).into_iter().map(move |offset| base + offset).collect::<Vec<usize>>()
+                } else {
+                    Vec::new()
+                };
+
+                let done = regions_done.fetch_add(1, Ordering::Relaxed) + 1;
+                let total_hits = hits_found.fetch_add(hits.len(), Ordering::Relaxed) + hits.len();
+
+                if done % 16 == 0 || done == regions_total {
+                    app_handle.emit(event_name, ScanProgressPayload { regions_done: done, regions_total, hits_found: total_hits }).ok();
+                }
+
+                hits
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Same as `scan`, but takes an already-compiled `Pattern` instead of a signature string —
+    /// for callers that scan the same signature repeatedly (e.g. `BaseAddressDumper::scan_and_resolve`
+    /// trying several memory regions against one AOB) and want to pay the parse cost once.
+    pub fn scan_pattern(memory: &Memory, start_address: usize, end_address: usize, pattern: &Pattern) -> Result<Vec<usize>, String> {
+        if pattern.is_empty() {
+            return Err("Invalid signature".to_string());
+        }
+
+        let regions = Self::enumerate_regions(memory, start_address, end_address);
+
+        let results: Vec<usize> = regions
+            .into_par_iter()
+            .flat_map(|(base, size)| {
+                if let Ok(buffer) = memory.read_bytes(base, size) {
+                    pattern.find_in_buffer(&buffer).into_iter().map(move |offset| base + offset).collect::<Vec<usize>>()
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Like `find_pattern_in_buffer`, but only reports matches whose absolute address
+    /// (`base_address + offset`) is a multiple of `alignment`.
+    pub fn find_pattern_in_buffer_aligned(buffer: &[u8], pattern: &[Option<u8>], base_address: usize, alignment: usize) -> Vec<usize> {
+        if alignment <= 1 {
+            return Self::find_pattern_in_buffer(buffer, pattern);
+        }
+
+        let m = pattern.len();
+        let mut matches = Vec::new();
+        if m == 0 || buffer.len() < m {
+            return matches;
+        }
+
+        // Start at the first offset whose absolute address is aligned, then step by `alignment`.
+        let mut i = (alignment - base_address % alignment) % alignment;
+        while i + m <= buffer.len() {
+            if Self::matches_pattern_at(buffer, pattern, i) {
+                matches.push(i);
+            }
+            i += alignment;
+        }
+        matches
+    }
+
+    /// Convenience wrapper over `scan` that restricts the region enumeration to one loaded
+    /// module's `[base, base+size)` instead of the whole address space — lets a caller target
+    /// e.g. just the UE game DLL, cutting scan time and false positives dramatically.
+    pub fn scan_module(memory: &Memory, module: &ModuleInfo, signature: &str) -> Result<Vec<usize>, String> {
+        Self::scan(memory, module.base, module.base + module.size, signature)
+    }
+
+    /// Same as `scan`, but applies `resolve` to each raw match instead of returning match sites
+    /// directly — the core primitive UE offset-dumping needs, since Unreal globals are typically
+    /// found via a `LEA`/`CALL` whose RIP-relative operand is the real target rather than the
+    /// match address itself.
+    pub fn scan_resolve(memory: &Memory, start_address: usize, end_address: usize, signature: &str, resolve: ResolveSpec) -> Result<Vec<usize>, String> {
+        let matches = Self::scan(memory, start_address, end_address, signature)?;
+
+        Ok(matches.into_iter().filter_map(|match_addr| resolve.apply(memory, match_addr)).collect())
+    }
+}
+
+/// Tells `Scanner::scan_resolve` how to turn a raw match address into the absolute address a
+/// `LEA`/`CALL`-style signature is actually hunting for: read a signed 32-bit little-endian
+/// displacement at `match_addr + disp_offset`, then add it to the address of the *next*
+/// instruction (`match_addr + instruction_length`) — the formula a RIP-relative operand needs.
+#[derive(Clone, Copy, Debug)]
+pub struct ResolveSpec {
+    pub disp_offset: usize,
+    pub instruction_length: usize,
+    /// When `true`, the resolved address is itself a pointer — read and return what it points to
+    /// instead, for patterns that land on a pointer rather than the target directly.
+    pub deref: bool,
+}
+
+impl ResolveSpec {
+    /// Applies this spec to one raw match address, returning `None` if the displacement or the
+    /// final dereference can't be read.
+    fn apply(&self, memory: &Memory, match_addr: usize) -> Option<usize> {
+        let disp_addr = match_addr.wrapping_add(self.disp_offset);
+        let disp: i32 = memory.read::<i32>(disp_addr).ok()?;
+        let target = match_addr.wrapping_add(self.instruction_length).wrapping_add_signed(disp as isize);
+
+        if self.deref {
+            memory.try_read_pointer(target)
+        } else {
+            Some(target)
+        }
+    }
 }
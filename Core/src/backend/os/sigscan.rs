@@ -0,0 +1,116 @@
+use crate::backend::os::pattern::Pattern;
+use crate::backend::os::process::Process;
+use crate::backend::os::scanner::Scanner;
+use crate::backend::os::x86;
+
+/// One operation applied to a raw signature match to turn "where the bytes matched" into "the
+/// absolute address this signature was actually hunting for" — RIP-relative
+/// displacement math and pointer-chasing, generalized into a chainable sequence instead of one
+/// fixed formula hand-picked per call site.
+#[derive(Clone, Copy, Debug)]
+pub enum PostMatchOp {
+    /// Advance the cursor by `n` bytes, e.g. skipping past a known-length instruction prefix.
+    Add(usize),
+    /// Resolve a RIP-relative operand: reads a 32-bit displacement at `cursor + offset` and
+    /// advances to `cursor + instruction_len + disp` (the next instruction's address plus the
+    /// displacement).
+    Rip { offset: usize, instruction_len: usize },
+    /// Same as `Rip`, but decodes `offset`/`instruction_len` from the bytes at the cursor via
+    /// `x86::decode_rip_operand` instead of hand-counting them. Only valid when the cursor points at the actual
+    /// RIP-relative instruction (no unrelated leading instruction to skip first).
+    RipAuto,
+    /// Follow the pointer at the cursor, `times` times in a row.
+    Deref { times: usize },
+}
+
+/// A compiled AOB signature plus the chain of `PostMatchOp`s that turns each raw match into a
+/// resolved address. Chained via `.add()`/`.rip()`/`.deref()` the same way `sdk_export`'s
+/// `FileBuilder`s compose rendering steps, just turning bytes into addresses here instead of
+/// addresses into text.
+pub struct Signature {
+    pattern: Pattern,
+    ops: Vec<PostMatchOp>,
+}
+
+impl Signature {
+    /// Compiles a signature string like `"48 8B 05 ? ? ? ?"` with an empty op chain — chain
+    /// `.add()`/`.rip()`/`.deref()` calls onto the result to describe how to turn a raw match
+    /// into the address actually being hunted for.
+    pub fn new(text: &str) -> Result<Self, String> {
+        Ok(Self { pattern: Pattern::parse(text)?, ops: Vec::new() })
+    }
+
+    pub fn add(mut self, n: usize) -> Self {
+        self.ops.push(PostMatchOp::Add(n));
+        self
+    }
+
+    pub fn rip(mut self, offset: usize, instruction_len: usize) -> Self {
+        self.ops.push(PostMatchOp::Rip { offset, instruction_len });
+        self
+    }
+
+    pub fn rip_auto(mut self) -> Self {
+        self.ops.push(PostMatchOp::RipAuto);
+        self
+    }
+
+    pub fn deref(mut self, times: usize) -> Self {
+        self.ops.push(PostMatchOp::Deref { times });
+        self
+    }
+
+    /// Scans `[start, end)` for this signature's raw bytes, without applying the op chain — for
+    /// a caller (like `BaseAddressDumper::scan_and_resolve`) that wants to attribute a per-match
+    /// failure (bad displacement, out-of-bounds result) back to the specific match that hit it.
+    pub fn raw_matches(&self, process: &Process, start: usize, end: usize) -> Result<Vec<usize>, String> {
+        Scanner::scan_pattern(&process.memory, start, end, &self.pattern)
+    }
+
+    /// Applies this signature's op chain to one raw match address, bailing out on the first op
+    /// that can't be carried out (e.g. an unreadable displacement or pointer).
+    pub fn resolve_at(&self, process: &Process, match_addr: usize) -> Result<usize, String> {
+        let mut cursor = match_addr;
+        for op in &self.ops {
+            cursor = match op {
+                PostMatchOp::Add(n) => cursor.wrapping_add(*n),
+                PostMatchOp::Rip { offset, instruction_len } => {
+                    let disp_addr = cursor.wrapping_add(*offset);
+                    let disp: i32 = process.memory.read::<i32>(disp_addr).map_err(|e| format!("Failed to read RIP displacement at 0x{:X}: {}", disp_addr, e))?;
+                    cursor.wrapping_add(*instruction_len).wrapping_add_signed(disp as isize)
+                }
+                PostMatchOp::RipAuto => {
+                    let bytes = process.memory.read_bytes(cursor, 16)?;
+                    let (disp_offset, instr_len) = x86::decode_rip_operand(&bytes)?;
+                    let disp_addr = cursor.wrapping_add(disp_offset);
+                    let disp: i32 = process.memory.read::<i32>(disp_addr).map_err(|e| format!("Failed to read RIP displacement at 0x{:X}: {}", disp_addr, e))?;
+                    cursor.wrapping_add(instr_len).wrapping_add_signed(disp as isize)
+                }
+                PostMatchOp::Deref { times } => {
+                    let mut next = cursor;
+                    for _ in 0..*times {
+                        next = process.memory.read_pointer(next).map_err(|e| format!("Failed to dereference 0x{:X}: {}", next, e))?;
+                    }
+                    next
+                }
+            };
+        }
+        Ok(cursor)
+    }
+
+    /// Scans `[start, end)` for this signature and returns every raw match's resolved address —
+    /// matches whose op chain fails to apply (an unreadable displacement, a null pointer mid-chain)
+    /// are skipped rather than aborting the scan, mirroring `BaseAddressDumper::scan_and_resolve`'s
+    /// "try every match until one looks like a valid pointer" loop.
+    pub fn resolve_all(&self, process: &Process, start: usize, end: usize) -> Result<Vec<usize>, String> {
+        let matches = self.raw_matches(process, start, end)?;
+        Ok(matches.into_iter().filter_map(|m| self.resolve_at(process, m).ok()).collect())
+    }
+
+    /// Convenience wrapper over `resolve_all` for a caller that only wants the first resolved hit
+    /// within the process's main module.
+    pub fn resolve_in_main_module(&self, process: &Process) -> Result<Option<usize>, String> {
+        let resolved = self.resolve_all(process, process.main_module_base, process.main_module_base + process.main_module_size)?;
+        Ok(resolved.into_iter().next())
+    }
+}
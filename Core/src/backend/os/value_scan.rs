@@ -0,0 +1,168 @@
+use crate::backend::os::memory::Memory;
+use crate::backend::os::scanner::Scanner;
+use rayon::prelude::*;
+
+/// Numeric type a `ValueScan` session searches for. Each decodes from little-endian bytes read
+/// straight out of the target process, the same way `Memory::read` does for a fixed Rust type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ValueType {
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl ValueType {
+    fn size(self) -> usize {
+        match self {
+            ValueType::I32 | ValueType::F32 => 4,
+            ValueType::I64 | ValueType::F64 => 8,
+        }
+    }
+
+    fn decode(self, bytes: &[u8]) -> ScanValue {
+        match self {
+            ValueType::I32 => ScanValue::I32(i32::from_le_bytes(bytes.try_into().unwrap())),
+            ValueType::I64 => ScanValue::I64(i64::from_le_bytes(bytes.try_into().unwrap())),
+            ValueType::F32 => ScanValue::F32(f32::from_le_bytes(bytes.try_into().unwrap())),
+            ValueType::F64 => ScanValue::F64(f64::from_le_bytes(bytes.try_into().unwrap())),
+        }
+    }
+}
+
+/// A decoded numeric value, tagged by which `ValueType` produced it so `Comparator` doesn't have
+/// to re-derive the type on every comparison.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub enum ScanValue {
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+}
+
+impl ScanValue {
+    fn as_f64(self) -> f64 {
+        match self {
+            ScanValue::I32(v) => v as f64,
+            ScanValue::I64(v) => v as f64,
+            ScanValue::F32(v) => v as f64,
+            ScanValue::F64(v) => v,
+        }
+    }
+}
+
+/// The Cheat-Engine-style filter `first_scan`/`next_scan` apply to a candidate address —
+/// `Changed`/`Unchanged`/`Increased`/`Decreased` compare against the previous round's value, so
+/// they only narrow anything on a `next_scan` (a `first_scan` has no previous round to compare
+/// against, and treats them the same as `Unknown`).
+#[derive(Debug, Clone, Copy)]
+pub enum Comparator {
+    /// Match any readable value — the usual `first_scan` filter when the target value isn't
+    /// known yet, snapshotting every candidate address so later rounds can narrow by behavior.
+    Unknown,
+    /// Match addresses whose decoded value exactly equals the given one.
+    ExactValue(ScanValue),
+    Changed,
+    Unchanged,
+    Increased,
+    Decreased,
+}
+
+impl Comparator {
+    fn matches(self, current: ScanValue, previous: Option<ScanValue>) -> bool {
+        match self {
+            Comparator::Unknown => true,
+            Comparator::ExactValue(target) => current == target,
+            Comparator::Changed => previous.map_or(true, |p| p != current),
+            Comparator::Unchanged => previous.map_or(true, |p| p == current),
+            Comparator::Increased => previous.map_or(true, |p| current.as_f64() > p.as_f64()),
+            Comparator::Decreased => previous.map_or(true, |p| current.as_f64() < p.as_f64()),
+        }
+    }
+}
+
+/// The retained candidate set and last-seen values of one iterative value-scan — narrowed round
+/// over round by `ValueScan::next_scan` the way Cheat Engine's scanner works.
+pub struct ScanSession {
+    value_type: ValueType,
+    /// Parallel to `values`: the address each retained candidate was found at.
+    addresses: Vec<usize>,
+    /// Parallel to `addresses`: the value read from that address as of the last scan round.
+    values: Vec<ScanValue>,
+}
+
+impl ScanSession {
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+
+    pub fn result_count(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn results(&self) -> Vec<(usize, ScanValue)> {
+        self.addresses.iter().copied().zip(self.values.iter().copied()).collect()
+    }
+}
+
+pub struct ValueScan;
+
+impl ValueScan {
+    /// Walks every committed, readable region in `[start_address, end_address)` (reusing
+    /// `Scanner::enumerate_regions`) and records every address, aligned to `value_type`'s own
+    /// size, whose value matches `filter` — `Comparator::Unknown` to snapshot everything,
+    /// `Comparator::ExactValue` to only keep addresses already holding a known value.
+    pub fn first_scan(memory: &Memory, start_address: usize, end_address: usize, value_type: ValueType, filter: Comparator) -> ScanSession {
+        let size = value_type.size();
+        let regions = Scanner::enumerate_regions(memory, start_address, end_address);
+
+        let (addresses, values): (Vec<usize>, Vec<ScanValue>) = regions
+            .into_par_iter()
+            .flat_map(|(base, region_size)| {
+                let mut hits = Vec::new();
+                if region_size < size {
+                    return hits;
+                }
+
+                if let Ok(buffer) = memory.read_bytes(base, region_size) {
+                    // Steps by the value's own size, matching the aligned "fast scan" mode
+                    // `Scanner::scan_aligned_cancellable` already defaults to for fixed-width
+                    // values — unaligned values won't be found, but scanning every byte offset
+                    // for a numeric search is rarely useful and far slower.
+                    let mut offset = 0;
+                    while offset + size <= region_size {
+                        let value = value_type.decode(&buffer[offset..offset + size]);
+                        if filter.matches(value, None) {
+                            hits.push((base + offset, value));
+                        }
+                        offset += size;
+                    }
+                }
+
+                hits
+            })
+            .unzip();
+
+        ScanSession { value_type, addresses, values }
+    }
+
+    /// Re-reads only `session`'s retained addresses and keeps the ones whose new value still
+    /// satisfies `comparator` against the value recorded in the previous round, narrowing the
+    /// candidate set without ever re-walking memory regions.
+    pub fn next_scan(memory: &Memory, session: &ScanSession, comparator: Comparator) -> ScanSession {
+        let size = session.value_type.size();
+
+        let (addresses, values): (Vec<usize>, Vec<ScanValue>) = session
+            .addresses
+            .par_iter()
+            .zip(session.values.par_iter())
+            .filter_map(|(&address, &previous)| {
+                let bytes = memory.read_bytes(address, size).ok()?;
+                let current = session.value_type.decode(&bytes);
+                comparator.matches(current, Some(previous)).then_some((address, current))
+            })
+            .unzip();
+
+        ScanSession { value_type: session.value_type, addresses, values }
+    }
+}
@@ -0,0 +1,74 @@
+/// Minimal x86-64 instruction-length decoder — just enough to locate a RIP-relative ModR/M
+/// operand's 32-bit displacement and the instruction's total length, the two numbers
+/// `sigscan::PostMatchOp::RipAuto` uses in place of a hand-counted `(offset, instruction_len)`
+/// pair per AOB signature.
+/// Not a general disassembler: opcodes outside the handful this crate's AOB signatures actually
+/// hit (LEA 8D, MOV 8B/89, CALL E8) are reported as unsupported rather than silently guessed.
+///
+/// `bytes` should start at the first prefix/opcode byte of the instruction (not an address that
+/// happens to precede it) and contain at least ~16 bytes — more than any instruction this
+/// decoder supports can be.
+pub fn decode_rip_operand(bytes: &[u8]) -> Result<(usize, usize), String> {
+    let mut idx = 0usize;
+
+    // Legacy prefixes: operand-size/address-size/lock/repne/rep overrides, segment overrides.
+    while idx < bytes.len() && matches!(bytes[idx], 0x66 | 0x67 | 0xF0 | 0xF2 | 0xF3 | 0x2E | 0x36 | 0x3E | 0x26 | 0x64 | 0x65) {
+        idx += 1;
+    }
+
+    // Optional REX prefix.
+    if idx < bytes.len() && (0x40..=0x4F).contains(&bytes[idx]) {
+        idx += 1;
+    }
+
+    let opcode = *bytes.get(idx).ok_or("Instruction truncated before opcode")?;
+    idx += 1;
+
+    // CALL rel32 has no ModR/M at all — the rel32 itself is the operand `PostMatchOp::Rip` treats as
+    // "the displacement" (target = next_instr_addr + rel32, the same formula as RIP-relative
+    // addressing).
+    if opcode == 0xE8 {
+        let disp_offset = idx;
+        return Ok((disp_offset, idx + 4));
+    }
+
+    let (two_byte, real_opcode) = if opcode == 0x0F {
+        let second = *bytes.get(idx).ok_or("Instruction truncated in 0F escape")?;
+        if second == 0x38 || second == 0x3A {
+            idx += 1;
+            let third = *bytes.get(idx).ok_or("Instruction truncated in 0F 38/3A map")?;
+            idx += 1;
+            (true, third)
+        } else {
+            idx += 1;
+            (true, second)
+        }
+    } else {
+        (false, opcode)
+    };
+
+    // Small opcode -> immediate-size table, covering only what this crate's signatures hit.
+    let immediate_size: usize = match (two_byte, real_opcode) {
+        (false, 0x8D) | (false, 0x8B) | (false, 0x89) => 0,
+        _ => return Err(format!("Unsupported opcode 0x{:02X} (two_byte={}) for RIP decode", real_opcode, two_byte)),
+    };
+
+    let modrm = *bytes.get(idx).ok_or("Instruction truncated before ModR/M")?;
+    idx += 1;
+    let md = modrm >> 6;
+    let rm = modrm & 0x7;
+
+    // A SIB byte follows whenever mod != 0b11 and rm == 0b100 — distinct from the RIP-relative
+    // case below (mod == 0b00, rm == 0b101), but still needs skipping to keep `idx` correct.
+    if md != 0b11 && rm == 0b100 {
+        idx += 1;
+    }
+
+    if md == 0b00 && rm == 0b101 {
+        let disp_offset = idx;
+        idx += 4; // disp32
+        Ok((disp_offset, idx + immediate_size))
+    } else {
+        Err("Instruction has no RIP-relative operand".to_string())
+    }
+}
@@ -1,9 +1,24 @@
-use crate::backend::os::process::Process;
+use crate::backend::os::process::{ModuleInfo, Process};
+use crate::backend::os::value_scan::ScanSession;
 use crate::backend::unreal::autoconfig::AutoConfig;
+use crate::backend::unreal::member_index::MemberIndex;
 use crate::backend::unreal::name_pool::FNamePool;
 use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offset_profile::{OffsetProfileStore, DEFAULT_PROFILE_NAME};
+use crate::backend::unreal::offsets::UEOffset;
+use crate::backend::unreal::property_write::FrozenProperty;
+use crate::backend::unreal::resolve_step::{default_sub_type_steps, ResolveStep};
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicBool, AtomicU8};
 use std::sync::{Arc, Mutex};
 
+/// `AppState::parse_run_state` values for the in-flight `parse_fname_pool`/`parse_guobject_array`
+/// job. Distinct from `parse_cancel`: a paused job's batch loop blocks (no work lost) instead of
+/// skipping ahead, so it can `resume_parse()` from exactly where it left off.
+pub const PARSE_STATE_IDLE: u8 = 0;
+pub const PARSE_STATE_RUNNING: u8 = 1;
+pub const PARSE_STATE_PAUSED: u8 = 2;
+
 /// Cached base addresses resolved by BaseAddressDumper.
 /// These are populated by commands in `base_address.rs` and consumed by other modules.
 #[derive(Default)]
@@ -17,11 +32,41 @@ pub struct BaseAddresses {
 /// The global application state for the memory scanner
 pub struct AppState {
     pub process: Mutex<Option<Process>>,
+    /// Every module (EXE + DLLs) loaded in the attached process, enumerated once at attach time —
+    /// searched by `resolve_rva` to turn an absolute address into a module-relative one.
+    pub modules: Mutex<Vec<ModuleInfo>>,
     pub auto_config: Mutex<Option<AutoConfig>>,
     pub object_manager: Arc<ObjectManager>,
     pub name_pool: Mutex<Option<Arc<FNamePool>>>,
     /// Resolved base addresses — written by `base_address` commands, read by all others.
     pub base_addresses: Mutex<BaseAddresses>,
+    /// One-time member-name index so `global_search`'s Member mode doesn't re-walk live memory.
+    pub member_index: Arc<MemberIndex>,
+    /// Set to request early termination of an in-flight `search_object_instances` scan.
+    pub scan_cancel: Arc<AtomicBool>,
+    /// Every `UEOffset` layout loaded from `offset_profiles.toml`, keyed by profile name.
+    pub offset_profiles: Mutex<OffsetProfileStore>,
+    /// Name of the profile every parsing command should read its `UEOffset` from.
+    pub active_profile_name: Mutex<String>,
+    /// Addresses registered by `freeze_property`, re-written on every tick of the background
+    /// freeze loop spawned by the first call to `freeze_property`.
+    pub frozen_properties: Arc<DashMap<usize, FrozenProperty>>,
+    /// Guards the one-time spawn of the freeze loop — there's no `.setup()` hook in this app,
+    /// so the loop is started lazily by whichever `freeze_property` call reaches it first.
+    pub freeze_loop_started: AtomicBool,
+    /// Set to request early termination of an in-flight `parse_fname_pool`/`parse_guobject_array`
+    /// job. Checked once per batch; cancelled batches are skipped rather than causing an error,
+    /// so the job still returns what it found so far plus the `next_batch` to resume from.
+    pub parse_cancel: Arc<AtomicBool>,
+    /// One of the `PARSE_STATE_*` constants above.
+    pub parse_run_state: Arc<AtomicU8>,
+    /// Step list `get_object_details` drives its sub-type resolution through. `None` means
+    /// "use [`default_sub_type_steps`] for whatever offset profile is active" — set by
+    /// `set_resolve_steps` once a build needs a different pointer-chase order than the default.
+    pub resolve_steps: Mutex<Option<Vec<ResolveStep>>>,
+    /// The in-progress `ValueScan` session, if any — narrowed by each `value_scan_next` call and
+    /// replaced wholesale by the next `value_scan_first`.
+    pub value_scan_session: Mutex<Option<ScanSession>>,
 }
 
 // Ensure AppState is Send + Sync for Tauri
@@ -30,6 +75,48 @@ unsafe impl Sync for AppState {}
 
 impl AppState {
     pub fn new() -> Self {
-        Self { process: Mutex::new(None), auto_config: Mutex::new(None), object_manager: Arc::new(ObjectManager::new()), name_pool: Mutex::new(None), base_addresses: Mutex::new(BaseAddresses::default()) }
+        Self {
+            process: Mutex::new(None),
+            modules: Mutex::new(Vec::new()),
+            auto_config: Mutex::new(None),
+            object_manager: Arc::new(ObjectManager::new()),
+            name_pool: Mutex::new(None),
+            base_addresses: Mutex::new(BaseAddresses::default()),
+            member_index: Arc::new(MemberIndex::new()),
+            scan_cancel: Arc::new(AtomicBool::new(false)),
+            offset_profiles: Mutex::new(OffsetProfileStore::load_or_default(&crate::backend::unreal::offset_profile::default_profile_path())),
+            active_profile_name: Mutex::new(DEFAULT_PROFILE_NAME.to_string()),
+            frozen_properties: Arc::new(DashMap::new()),
+            freeze_loop_started: AtomicBool::new(false),
+            parse_cancel: Arc::new(AtomicBool::new(false)),
+            parse_run_state: Arc::new(AtomicU8::new(PARSE_STATE_IDLE)),
+            resolve_steps: Mutex::new(None),
+            value_scan_session: Mutex::new(None),
+        }
+    }
+
+    /// The `UEOffset` layout every parsing command should use: whatever the active profile
+    /// resolves to, falling back to the built-in default if the active profile was removed.
+    pub fn active_offsets(&self) -> UEOffset {
+        let name = self.active_profile_name.lock().unwrap().clone();
+        self.offset_profiles.lock().unwrap().get(&name).unwrap_or_default()
+    }
+
+    /// The `ResolveStep` program `get_object_details` should drive its sub-type resolution
+    /// through: whatever was installed via `set_resolve_steps`, falling back to
+    /// [`default_sub_type_steps`] built from the active offset profile.
+    pub fn active_resolve_steps(&self) -> Vec<ResolveStep> {
+        match self.resolve_steps.lock().unwrap().clone() {
+            Some(steps) => steps,
+            None => default_sub_type_steps(&self.active_offsets()),
+        }
+    }
+
+    /// Finds the module containing `addr` and returns its name alongside the address minus that
+    /// module's base — the same `"ModuleName.dll+0x1234"` shape every dumped function address
+    /// should be reported in, so it stays valid across ASLR relaunches.
+    pub fn resolve_rva(&self, addr: usize) -> Option<(String, usize)> {
+        let modules = self.modules.lock().unwrap();
+        modules.iter().find(|m| addr >= m.base && addr < m.base + m.size).map(|m| (m.name.clone(), addr - m.base))
     }
 }
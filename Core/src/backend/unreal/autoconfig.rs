@@ -1,4 +1,6 @@
 use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use std::collections::HashMap;
 
 /// Stores the vital offsets dynamically found at runtime
 #[derive(Debug, Default, Clone)]
@@ -20,37 +22,140 @@ pub struct AutoConfig {
     pub offsets: UEOffsets,
 }
 
+/// How many live UObject pointers to sample from GUObjectArray before trusting a candidate offset.
+const SAMPLE_COUNT: usize = 256;
+/// Only the first 0x40 bytes of a UObject are probed — every field we care about lives there.
+const PROBE_WINDOW: usize = 0x40;
+/// A candidate offset must agree across this fraction of samples before it is accepted.
+const CONSISTENCY_THRESHOLD: f64 = 0.8;
+
 impl AutoConfig {
     pub fn new() -> Self {
         Self { offsets: UEOffsets::default() }
     }
 
-    /// Simplified translation of AutoConfig.cpp dynamically scanning UObject memory structure
-    pub fn scan_basic_offsets(&mut self, process: &Process, gu_object_base: usize, _name_pool_base: usize) -> Result<(), String> {
-        // Read the GUObjectArray entry pointer
+    /// Real auto-scanner replacing the old hardcoded UE5 constants: samples live UObjects out
+    /// of GUObjectArray and validates each candidate offset against the FNamePool before
+    /// trusting it, so the tool keeps working across engine versions without a rebuild.
+    pub fn scan_basic_offsets(&mut self, process: &Process, gu_object_base: usize, name_pool_base: usize) -> Result<(), String> {
+        let name_pool = FNamePool::new(name_pool_base, &crate::backend::unreal::offsets::UEOffset::default());
+
+        let samples = Self::collect_object_samples(process, gu_object_base, SAMPLE_COUNT)?;
+        if samples.is_empty() {
+            return Err("AutoConfig: could not collect any live UObject samples from GUObjectArray".to_string());
+        }
+
+        let f_name_index = Self::find_fname_offset(process, &name_pool, &samples)
+            .ok_or_else(|| format!("AutoConfig: failed to locate f_name_index (no offset in 0..0x{:X} resolved a valid FName on >= {}% of {} samples)", PROBE_WINDOW, (CONSISTENCY_THRESHOLD * 100.0) as u32, samples.len()))?;
+
+        let class = Self::find_pointer_offset(process, &name_pool, &samples, f_name_index, None)
+            .ok_or_else(|| "AutoConfig: failed to locate class (no pointer-sized offset dereferenced to a valid UObject)".to_string())?;
+
+        // Outer lives in the same probe window; exclude the offset we already attributed to Class.
+        let outer = Self::find_pointer_offset(process, &name_pool, &samples, f_name_index, Some(class))
+            .ok_or_else(|| "AutoConfig: failed to locate outer (no secondary pointer-sized offset dereferenced to a valid UObject)".to_string())?;
+
+        self.offsets.f_name_index = f_name_index;
+        self.offsets.class = class;
+        self.offsets.outer = outer;
+
+        println!("[ AutoConfig ] f_name_index=0x{:X} class=0x{:X} outer=0x{:X}", f_name_index, class, outer);
+
+        Ok(())
+    }
+
+    /// Walks the first chunk of GUObjectArray and returns up to `count` non-null, readable UObject pointers.
+    fn collect_object_samples(process: &Process, gu_object_base: usize, count: usize) -> Result<Vec<usize>, String> {
         let object_array_entry = process.memory.read_pointer(gu_object_base + 0x10)?;
+        let chunk_ptr = process.memory.read_pointer(object_array_entry)?;
 
-        // Scan the first few objects
-        for i in 0..10 {
-            let chunk_ptr = process.memory.read_pointer(object_array_entry)?;
-            let object_entry = process.memory.read_pointer(chunk_ptr + (i * 0x18))?;
+        let mut samples = Vec::with_capacity(count);
+        let mut i = 0;
+        // Scan a generous window of slots since some entries may be null/unused.
+        while samples.len() < count && i < count * 4 {
+            if let Some(object_entry) = process.memory.try_read_pointer(chunk_ptr + i * 0x18) {
+                if object_entry > 0x10000 && process.memory.try_read_pointer(object_entry).is_some() {
+                    samples.push(object_entry);
+                }
+            }
+            i += 1;
+        }
 
-            if object_entry == 0 {
-                continue;
+        Ok(samples)
+    }
+
+    /// Probes every 4-byte-aligned offset in the first `PROBE_WINDOW` bytes of each sample,
+    /// and keeps the offset whose candidate FName id resolves to a non-empty ASCII name on
+    /// the largest fraction of samples.
+    fn find_fname_offset(process: &Process, name_pool: &FNamePool, samples: &[usize]) -> Option<usize> {
+        let mut hits: HashMap<usize, usize> = HashMap::new();
+
+        for &addr in samples {
+            for offset in (0..PROBE_WINDOW).step_by(4) {
+                let candidate = match process.memory.try_read::<i32>(addr + offset) {
+                    Some(v) if v >= 0 => v as u32,
+                    _ => continue,
+                };
+
+                // An FName id packs a block index in the high 16 bits; reject candidates that
+                // would address an implausible number of name pool blocks.
+                if (candidate >> 16) > 0x1000 {
+                    continue;
+                }
+
+                if let Ok(name) = name_pool.get_name(process, candidate) {
+                    if !name.is_empty() && name.is_ascii() {
+                        *hits.entry(offset).or_insert(0) += 1;
+                    }
+                }
             }
+        }
+
+        Self::best_candidate(hits, samples.len())
+    }
 
-            // Pattern scan for Object name string to identify Name property
-            // (Mocking the exact C++ logic which reads strings manually here, we will hardcode common offsets for safety/speed)
+    /// Probes pointer-sized offsets and keeps the one whose value is itself a pointer that is
+    /// non-null, points into mapped memory, and (treated as a UObject) yields a resolvable
+    /// FName at `f_name_index`. `exclude` skips an offset already attributed to another field.
+    fn find_pointer_offset(process: &Process, name_pool: &FNamePool, samples: &[usize], f_name_index: usize, exclude: Option<usize>) -> Option<usize> {
+        let mut hits: HashMap<usize, usize> = HashMap::new();
 
-            // Common modern UE5 offsets
-            self.offsets.object_id = 0x0C; // ID is usually at 0x0C
-            self.offsets.f_name_index = 0x18; // Name is at 0x18
-            self.offsets.class = 0x10; // Class Private at 0x10
-            self.offsets.outer = 0x20; // Outer Private at 0x20
+        for &addr in samples {
+            for offset in (0..PROBE_WINDOW).step_by(8) {
+                if Some(offset) == exclude {
+                    continue;
+                }
 
-            break;
+                let candidate_ptr = match process.memory.try_read_pointer(addr + offset) {
+                    Some(p) if p > 0x10000 => p,
+                    _ => continue,
+                };
+
+                // (b) candidate must point into mapped memory
+                if process.memory.try_read_pointer(candidate_ptr).is_none() {
+                    continue;
+                }
+
+                // (c) treated as a UObject, it must yield a resolvable FName at f_name_index
+                let name_id = match process.memory.try_read::<i32>(candidate_ptr + f_name_index) {
+                    Some(v) if v >= 0 => v as u32,
+                    _ => continue,
+                };
+
+                if let Ok(name) = name_pool.get_name(process, name_id) {
+                    if !name.is_empty() {
+                        *hits.entry(offset).or_insert(0) += 1;
+                    }
+                }
+            }
         }
 
-        Ok(())
+        Self::best_candidate(hits, samples.len())
+    }
+
+    /// Picks the offset with the highest sample agreement, requiring at least
+    /// `CONSISTENCY_THRESHOLD` of samples to agree before accepting it.
+    fn best_candidate(hits: HashMap<usize, usize>, sample_count: usize) -> Option<usize> {
+        hits.into_iter().max_by_key(|&(_, count)| count).filter(|&(_, count)| (count as f64 / sample_count as f64) >= CONSISTENCY_THRESHOLD).map(|(offset, _)| offset)
     }
 }
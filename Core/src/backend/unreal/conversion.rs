@@ -0,0 +1,176 @@
+/// Declarative live-value *rendering*, independent of `member_value::PropertyKind`'s *reading*
+/// dispatch. Where `PropertyKind` decides how to pull bytes out of process memory, `Conversion`
+/// decides how to turn the decoded primitive into display text, and — unlike `PropertyKind` —
+/// can be overridden per-property by the caller (e.g. view an IntProperty as hex instead of
+/// decimal, or an Int64Property as a Unix timestamp).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    Bytes,
+    Integer,
+    IntegerHex,
+    Float(usize),  // decimal places, f32-backed (FloatProperty)
+    Double(usize), // decimal places, f64-backed (DoubleProperty)
+    Boolean,
+    Name,
+    FString,
+    /// FDateTime's int64 tick count (100ns units since 0001-01-01), rendered with an optional
+    /// caller-supplied `strftime`-style format (`%Y`/`%m`/`%d`/`%H`/`%M`/`%S`); `None` uses the
+    /// same `"YYYY-MM-DD HH:MM:SS UTC"` shape this crate has always rendered.
+    Timestamp(Option<String>),
+    /// StructProperty (and, as `default_for`'s pick, Array/Map/SetProperty) where the caller
+    /// already decoded the field count itself (e.g. `walk_instance_properties`'s struct
+    /// recursion) — renders as `"{ N field(s) }"` so that path goes through the same registry
+    /// instead of a one-off `format!` at the call site. `walk_instance_properties`'s
+    /// ArrayProperty path formats its own `"[N element(s)]"` text instead.
+    Struct,
+}
+
+/// A value already decoded out of process memory, handed to `Conversion::render` to be turned
+/// into display text. Decoding (which offset to read, how many bytes) stays the caller's job —
+/// this enum only carries the result.
+#[derive(Debug, Clone)]
+pub enum RawValue {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+impl Conversion {
+    /// Picks the default rendering for a property, purely from its UE type name — same
+    /// fallback `get_instance_details` used before this registry existed.
+    pub fn default_for(property_type: &str) -> Self {
+        let t = property_type.to_lowercase();
+        if t.contains("boolproperty") {
+            Conversion::Boolean
+        } else if t.contains("nameproperty") {
+            Conversion::Name
+        } else if t.contains("strproperty") || t.contains("textproperty") {
+            Conversion::FString
+        } else if t.contains("doubleproperty") {
+            Conversion::Double(5)
+        } else if t.contains("floatproperty") {
+            Conversion::Float(3)
+        } else if t.contains("intproperty") || t.contains("int32") || t.contains("byteproperty") {
+            Conversion::Integer
+        } else if t.contains("structproperty") || t.contains("arrayproperty") || t.contains("mapproperty") || t.contains("setproperty") {
+            Conversion::Struct
+        } else {
+            Conversion::Bytes
+        }
+    }
+
+    /// Parses a frontend-supplied format spec like `"float:%.2f"`, `"int:hex"`, `"bytes"`, or
+    /// `"timestamp"`. Returns `None` for anything unrecognized, so the caller can fall back to
+    /// `default_for` instead of silently misrendering the value.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (kind, arg) = match spec.split_once(':') {
+            Some((k, a)) => (k, Some(a)),
+            None => (spec, None),
+        };
+
+        match kind.to_lowercase().as_str() {
+            "bool" | "boolean" => Some(Conversion::Boolean),
+            "name" => Some(Conversion::Name),
+            "string" | "fstring" | "text" | "str" => Some(Conversion::FString),
+            "bytes" => Some(Conversion::Bytes),
+            "struct" => Some(Conversion::Struct),
+            "timestamp" => Some(Conversion::Timestamp(arg.map(str::to_string))),
+            "int" | "integer" => match arg {
+                Some(a) if a.eq_ignore_ascii_case("hex") => Some(Conversion::IntegerHex),
+                _ => Some(Conversion::Integer),
+            },
+            "float" => {
+                let places = arg.and_then(|a| a.trim_start_matches("%.").trim_end_matches('f').parse().ok()).unwrap_or(3);
+                Some(Conversion::Float(places))
+            }
+            "double" => {
+                let places = arg.and_then(|a| a.trim_start_matches("%.").trim_end_matches('f').parse().ok()).unwrap_or(5);
+                Some(Conversion::Double(places))
+            }
+            _ => None,
+        }
+    }
+
+    /// Renders a decoded value as display text. A `RawValue` that doesn't match the conversion's
+    /// expected shape (e.g. `Timestamp` applied to a `Str`) falls back to the value's own default
+    /// `Display`-style text rather than panicking — a mismatched override is a display-quality
+    /// issue, not a reason to fail the whole property walk.
+    pub fn render(&self, raw: &RawValue) -> String {
+        match (self, raw) {
+            (Conversion::Boolean, RawValue::Bool(b)) => if *b { "True" } else { "False" }.to_string(),
+            (Conversion::Name, RawValue::Str(s)) | (Conversion::FString, RawValue::Str(s)) => s.clone(),
+            (Conversion::Integer, RawValue::Int(v)) => v.to_string(),
+            (Conversion::IntegerHex, RawValue::Int(v)) => format!("0x{:X}", v),
+            (Conversion::Float(places), RawValue::Float(v)) => format!("{:.*}", places, v),
+            (Conversion::Double(places), RawValue::Float(v)) => format!("{:.*}", places, v),
+            (Conversion::Timestamp(fmt), RawValue::Int(ticks)) => format_fdatetime_ticks(*ticks, fmt.as_deref()),
+            (Conversion::Struct, RawValue::Int(count)) => format!("{{ {} field(s) }}", count),
+            (Conversion::Bytes, RawValue::Int(v)) => format!("0x{:X}", v),
+            _ => render_fallback(raw),
+        }
+    }
+}
+
+fn render_fallback(raw: &RawValue) -> String {
+    match raw {
+        RawValue::Int(v) => v.to_string(),
+        RawValue::Float(v) => v.to_string(),
+        RawValue::Bool(b) => b.to_string(),
+        RawValue::Str(s) => s.clone(),
+    }
+}
+
+/// Splits Unix epoch seconds into `(year, month, day, hour, minute, second)`. Hand-rolled
+/// (civil-from-days, the same algorithm `libc++`/`absl::CivilDay` use) rather than pulling in a
+/// date/time crate for a single display format.
+fn civil_from_epoch_seconds(epoch_seconds: i64) -> (i64, u32, u32, i64, i64, i64) {
+    let days = epoch_seconds.div_euclid(86400);
+    let secs_of_day = epoch_seconds.rem_euclid(86400);
+
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+
+    let hour = secs_of_day / 3600;
+    let minute = (secs_of_day % 3600) / 60;
+    let second = secs_of_day % 60;
+
+    (year, month, day, hour, minute, second)
+}
+
+/// Formats Unix epoch seconds as `YYYY-MM-DD HH:MM:SS UTC`.
+fn format_unix_timestamp(epoch_seconds: i64) -> String {
+    let (year, month, day, hour, minute, second) = civil_from_epoch_seconds(epoch_seconds);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, hour, minute, second)
+}
+
+/// Ticks since `0001-01-01` at 100ns resolution — the tick definition `FDateTime` shares with
+/// .NET's `System.DateTime`, which is where this constant (ticks from `0001-01-01` to the Unix
+/// epoch) comes from.
+const TICKS_PER_SECOND: i64 = 10_000_000;
+const TICKS_AT_UNIX_EPOCH: i64 = 621_355_968_000_000_000;
+
+/// Renders an `FDateTime`'s raw tick count with an optional `strftime`-ish format string
+/// (`%Y %m %d %H %M %S`); `None` falls back to the classic `"YYYY-MM-DD HH:MM:SS UTC"` shape.
+fn format_fdatetime_ticks(ticks: i64, format: Option<&str>) -> String {
+    let epoch_seconds = (ticks - TICKS_AT_UNIX_EPOCH).div_euclid(TICKS_PER_SECOND);
+    let Some(fmt) = format else {
+        return format_unix_timestamp(epoch_seconds);
+    };
+
+    let (year, month, day, hour, minute, second) = civil_from_epoch_seconds(epoch_seconds);
+    fmt.replace("%Y", &format!("{:04}", year))
+        .replace("%m", &format!("{:02}", month))
+        .replace("%d", &format!("{:02}", day))
+        .replace("%H", &format!("{:02}", hour))
+        .replace("%M", &format!("{:02}", minute))
+        .replace("%S", &format!("{:02}", second))
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Structured error for `BaseAddressDumper`'s AOB-scan-and-resolve pipeline, replacing the flat
+/// `Result<_, String>` every other function in this crate still uses. Specific enough that a
+/// caller can tell "signature never matched" apart from "matched, but the resolved pointer was
+/// garbage" without parsing an opaque string — and `Display` produces exactly the message this
+/// module used to hand-format at each `println!` call site, so nothing is printed twice.
+#[derive(Debug, Clone)]
+pub enum DumpError {
+    /// AOB `aob_index`'s signature string couldn't be compiled into a `Pattern`.
+    InvalidSignature { aob_index: usize, reason: String },
+    /// AOB `aob_index` never matched anywhere in the scanned range.
+    SignatureNotFound { aob_index: usize },
+    /// Scanning for AOB `aob_index` itself failed (not "no match" — the scan call errored).
+    ScanFailed { aob_index: usize, reason: String },
+    /// A memory read needed to resolve AOB `aob_index`'s match at `address` failed.
+    ReadFailed { aob_index: usize, address: usize, reason: String },
+    /// AOB `aob_index`'s match at `address` resolved to `resolved`, which is outside valid
+    /// user-space bounds.
+    ResolvedOutOfBounds { aob_index: usize, address: usize, resolved: usize },
+    /// Every known AOB signature for `target_name` was tried — see `attempts` for the full scan
+    /// trail — and none of them resolved to a usable address.
+    AllSignaturesFailed { target_name: String, attempts: Vec<DumpError> },
+    /// `detect_element_size` exhausted its probe range (`i_raw`/`k` space) without finding an
+    /// element size that produced consistent object indices.
+    ElementSizeNotFound,
+}
+
+impl fmt::Display for DumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumpError::InvalidSignature { aob_index, reason } => write!(f, "AOB {} failed to compile: {}", aob_index, reason),
+            DumpError::SignatureNotFound { aob_index } => write!(f, "AOB {} failed: Signature not found in memory.", aob_index),
+            DumpError::ScanFailed { aob_index, reason } => write!(f, "AOB {} failed during scanning pipeline: {}", aob_index, reason),
+            DumpError::ReadFailed { aob_index, address, reason } => write!(f, "AOB {} failed at 0x{:X}: Could not read displacement. Error: {}", aob_index, address, reason),
+            DumpError::ResolvedOutOfBounds { aob_index, address, resolved } => write!(f, "AOB {} failed at 0x{:X}: Resolved address (0x{:X}) is out of valid user-space memory bounds.", aob_index, address, resolved),
+            DumpError::AllSignaturesFailed { target_name, attempts } => write!(f, "Could not find {} with any of the known AOB signatures ({} attempt(s) tried)", target_name, attempts.len()),
+            DumpError::ElementSizeNotFound => write!(f, "Could not auto-detect element size"),
+        }
+    }
+}
+
+/// Lets every existing `Result<_, String>`-returning call site (Tauri commands propagate errors
+/// to the frontend as plain strings) keep using `?` against `DumpError` unchanged.
+impl From<DumpError> for String {
+    fn from(err: DumpError) -> String {
+        err.to_string()
+    }
+}
@@ -0,0 +1,129 @@
+use crate::backend::unreal::object_array::{ObjectData, ObjectManager};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+/// Identifies the exact parse a dump file was taken from, so a stale dump (different game
+/// build, moved module base, or a different offset profile) is rejected instead of silently
+/// hydrating addresses that no longer line up with the running process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpMeta {
+    pub process_name: String,
+    pub module_base: usize,
+    pub offset_profile: String,
+}
+
+const FORMAT_VERSION: u32 = 1;
+const HEADER_PREFIX: &str = "# UEDP_JH object dump";
+
+/// Default on-disk location for a process's dump, relative to the working directory the app
+/// was launched from — mirrors `offset_profile::default_profile_path`.
+pub fn default_dump_path(process_name: &str) -> PathBuf {
+    PathBuf::from("dumps").join(format!("{}.dump", process_name))
+}
+
+/// Serializes every cached `ObjectData` to a tab-separated text file, one object per line,
+/// preceded by a small `key=value` header so a later reattach can tell whether the dump still
+/// matches the running process before trusting its addresses. Deliberately hand-rolled (like
+/// `usmap_export`/`sdk_export`) rather than pulled through serde — the cache can hold millions
+/// of rows and a flat line format is trivial to stream without an extra dependency.
+pub fn save_dump(path: &Path, obj_mgr: &ObjectManager, meta: &DumpMeta) -> Result<usize, String> {
+    let mut out = String::new();
+    out.push_str(&format!("{} v{}\n", HEADER_PREFIX, FORMAT_VERSION));
+    out.push_str(&format!("process_name={}\n", meta.process_name));
+    out.push_str(&format!("module_base=0x{:X}\n", meta.module_base));
+    out.push_str(&format!("offset_profile={}\n", meta.offset_profile));
+    out.push_str(&format!("object_count={}\n", obj_mgr.cache_by_address.len()));
+    out.push_str("---\n");
+
+    for entry in obj_mgr.cache_by_address.iter() {
+        let obj = entry.value();
+        // Tab-separated; name/type_name/full_name come from FNamePool data, which never
+        // contains tabs or newlines, so no escaping is needed.
+        out.push_str(&format!("{:X}\t{}\t{}\t{}\t{}\t{:X}\t{:X}\n", obj.address, obj.id, obj.name, obj.type_name, obj.full_name, obj.outer, obj.class_ptr));
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create dump directory: {}", e))?;
+    }
+
+    let count = obj_mgr.cache_by_address.len();
+    std::fs::write(path, out).map_err(|e| format!("Failed to write dump: {}", e))?;
+    Ok(count)
+}
+
+/// Parses just the header of a dump file, so callers can check it matches the current
+/// process/module/profile before paying the cost of loading its (potentially huge) object table.
+pub fn read_meta(path: &Path) -> Result<DumpMeta, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("Failed to read dump: {}", e))?;
+    let mut lines = text.lines();
+
+    let header = lines.next().ok_or("Empty dump file")?;
+    if !header.starts_with(HEADER_PREFIX) {
+        return Err("Not a UEDP_JH object dump".to_string());
+    }
+
+    let mut meta = DumpMeta { process_name: String::new(), module_base: 0, offset_profile: String::new() };
+
+    for line in lines {
+        if line == "---" {
+            break;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            match key {
+                "process_name" => meta.process_name = value.to_string(),
+                "module_base" => meta.module_base = usize::from_str_radix(value.trim_start_matches("0x"), 16).unwrap_or(0),
+                "offset_profile" => meta.offset_profile = value.to_string(),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(meta)
+}
+
+/// Loads a dump's object table into `obj_mgr`, replacing whatever is already cached. Callers
+/// that care about staleness should check `read_meta` against the live process first — this
+/// does not re-validate, so it also works for purely offline/static inspection.
+pub fn load_dump(path: &Path, obj_mgr: &ObjectManager) -> Result<usize, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("Failed to read dump: {}", e))?;
+    let mut lines = text.lines();
+
+    let header = lines.next().ok_or("Empty dump file")?;
+    if !header.starts_with(HEADER_PREFIX) {
+        return Err("Not a UEDP_JH object dump".to_string());
+    }
+
+    for line in lines.by_ref() {
+        if line == "---" {
+            break;
+        }
+    }
+
+    obj_mgr.cache_by_address.clear();
+    obj_mgr.cache_by_id.clear();
+
+    let mut count = 0usize;
+    for line in lines {
+        let fields: Vec<&str> = line.splitn(7, '\t').collect();
+        if fields.len() != 7 {
+            continue;
+        }
+
+        let address = usize::from_str_radix(fields[0], 16).unwrap_or(0);
+        let id: i32 = fields[1].parse().unwrap_or(0);
+        let outer = usize::from_str_radix(fields[5], 16).unwrap_or(0);
+        let class_ptr = usize::from_str_radix(fields[6], 16).unwrap_or(0);
+
+        let obj = ObjectData { address, id, name: fields[2].to_string(), type_name: fields[3].to_string(), full_name: fields[4].to_string(), outer, class_ptr };
+
+        // Mirrors `ObjectManager::try_save_object`'s id-cache guard.
+        if id > 0 && (id as u32) < 0xFFFFFFFF && !obj.type_name.contains("Property") {
+            obj_mgr.cache_by_id.insert(id, address);
+        }
+        obj_mgr.cache_by_address.insert(address, obj);
+        count += 1;
+    }
+
+    obj_mgr.total_object_count.store(count, Ordering::Relaxed);
+    Ok(count)
+}
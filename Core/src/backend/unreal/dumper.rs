@@ -1,24 +1,12 @@
 use crate::backend::os::process::Process;
-use crate::backend::os::scanner::Scanner;
+use crate::backend::os::sigscan::Signature;
+use crate::backend::unreal::dump_error::DumpError;
 
 pub struct BaseAddressDumper;
 
 impl BaseAddressDumper {
-    /// Resolves a RIP-relative address using an instruction address, the offset of the 32-bit displacement, and the total instruction length.
-    /// target_address = instruction_address + instruction_length + displacement
-    pub fn resolve_rip(process: &Process, instr_addr: usize, disp_offset: usize, instr_len: usize) -> Result<usize, String> {
-        // Read 32-bit signed displacement
-        let disp: i32 = process.memory.read::<i32>(instr_addr + disp_offset)?;
-
-        // Calculate absolute address
-        // The cast to isize handles negative displacements properly when added to a usize base
-        let target = (instr_addr + instr_len).wrapping_add_signed(disp as isize);
-
-        Ok(target)
-    }
-
     /// Attempts to find the FNamePool base address
-    pub fn get_fname_pool(process: &Process) -> Result<usize, String> {
+    pub fn get_fname_pool(process: &Process) -> Result<usize, DumpError> {
         let aobs = vec![
             // AOB, displacement_offset, instruction_length
             ("4C 8D 05 ? ? ? ? EB 16 48 8D 0D ? ? ? ? E8", 3, 7),
@@ -34,15 +22,22 @@ impl BaseAddressDumper {
 
     /// Attempts to find the GUObjectArray base address and element size
     /// Returns (base_address, element_size)
-    pub fn get_guobject_array_with_element_size(process: &Process) -> Result<(usize, usize), String> {
+    pub fn get_guobject_array_with_element_size(process: &Process) -> Result<(usize, usize), DumpError> {
         let base = Self::get_guobject_array(process)?;
-        let element_size = Self::detect_element_size(process, base)?;
+        let element_size = match Self::detect_element_size(process, base) {
+            Ok(size) => size,
+            Err(e) => {
+                // Fallback: default to 0x18 (most common for UE4 64-bit)
+                println!("[ GUObjectArray ] {}, defaulting to 0x18", e);
+                0x18
+            }
+        };
         println!("  -> GUObjectArray ElementSize = 0x{:X}", element_size);
         Ok((base, element_size))
     }
 
     /// Attempts to find the GUObjectArray base address
-    pub fn get_guobject_array(process: &Process) -> Result<usize, String> {
+    pub fn get_guobject_array(process: &Process) -> Result<usize, DumpError> {
         let aobs = vec![
             // AOB, displacement_offset, instruction_length
             ("44 8B ? ? ? 48 8D 05 ? ? ? ? ? ? ? ? ? 48 89 71 10", 8, 12),
@@ -60,7 +55,7 @@ impl BaseAddressDumper {
     /// Detect GUObjectArray element size by probing, matching C++ ValidateGUObjectArray logic.
     /// Iterates byte offsets from the base, reads the first valid chunk pointer,
     /// then probes element sizes k=0x4..0x1C to find which one produces consistent object indices.
-    fn detect_element_size(process: &Process, base_address: usize) -> Result<usize, String> {
+    fn detect_element_size(process: &Process, base_address: usize) -> Result<usize, DumpError> {
         // Scan offsets -0x50..0x200 from base to find a valid multi-level pointer entry
         for i_raw in (-0x50i32..=0x200).step_by(4) {
             let entry_addr = base_address.wrapping_add(i_raw as usize);
@@ -144,53 +139,104 @@ impl BaseAddressDumper {
             }
         }
 
-        // Fallback: default to 0x18 (most common for UE4 64-bit)
-        println!("[ GUObjectArray ] Could not auto-detect element size, defaulting to 0x18");
-        Ok(0x18)
+        Err(DumpError::ElementSizeNotFound)
     }
 
-    /// Attempts to find the GWorld base address
-    pub fn get_gworld(process: &Process) -> Result<usize, String> {
-        let aobs = vec![("48 8B 1D ? ? ? ? 48 85 DB 74 33 41 B0 01", 3, 7)];
+    /// Attempts to find the GWorld base address. The signature matches exactly at the target
+    /// `mov rbx, [rip+disp]` instruction (no unrelated leading instruction to skip), so this is
+    /// resolved with `Signature::rip_auto` instead of a hand-counted `(disp_offset, instr_len)`
+    /// pair — one fewer magic-number tuple to keep in sync with the signature.
+    pub fn get_gworld(process: &Process) -> Result<usize, DumpError> {
+        let signature = "48 8B 1D ? ? ? ? 48 85 DB 74 33 41 B0 01";
+        println!("Scanning for GWorld...");
+
+        let sig = Signature::new(signature).map_err(|reason| DumpError::InvalidSignature { aob_index: 0, reason })?.rip_auto();
+        let results = sig.raw_matches(process, process.main_module_base, process.main_module_base + process.main_module_size).map_err(|reason| DumpError::ScanFailed { aob_index: 0, reason })?;
+        if results.is_empty() {
+            return Err(DumpError::AllSignaturesFailed { target_name: "GWorld".to_string(), attempts: vec![DumpError::SignatureNotFound { aob_index: 0 }] });
+        }
+
+        let mut attempts = Vec::new();
+        for &addr in &results {
+            match sig.resolve_at(process, addr) {
+                Ok(resolved) if resolved > 0x10000 && resolved < 0x7FFFFFFFFFFF => {
+                    println!("  -> Found GWorld at 0x{:X} [{}]", resolved, signature);
+                    return Ok(resolved);
+                }
+                Ok(resolved) => {
+                    let e = DumpError::ResolvedOutOfBounds { aob_index: 0, address: addr, resolved };
+                    println!("  -> {}", e);
+                    attempts.push(e);
+                }
+                Err(reason) => {
+                    let e = DumpError::ReadFailed { aob_index: 0, address: addr, reason };
+                    println!("  -> {}", e);
+                    attempts.push(e);
+                }
+            }
+        }
 
-        Self::scan_and_resolve(process, aobs, "GWorld")
+        Err(DumpError::AllSignaturesFailed { target_name: "GWorld".to_string(), attempts })
     }
 
-    /// Generic scanner that goes through a list of (AOB, disp_offset, instr_len),
-    /// scans the main module, and resolves the RIP relative pointer to find the global address.
-    fn scan_and_resolve(process: &Process, aobs: Vec<(&str, usize, usize)>, target_name: &str) -> Result<usize, String> {
-        for (idx, (aob, disp_offset, instr_len)) in aobs.iter().enumerate() {
+    /// Generic scanner that goes through a list of (AOB, disp_offset, instr_len), scans the main
+    /// module, and resolves each match's RIP-relative pointer to find the global address. Each
+    /// AOB is compiled into a `Signature` (a `Pattern` plus a one-op `Rip` chain) before any
+    /// scanning starts, and each match is resolved through that chain.
+    fn scan_and_resolve(process: &Process, aobs: Vec<(&str, usize, usize)>, target_name: &str) -> Result<usize, DumpError> {
+        let compiled: Vec<(&str, Result<Signature, String>)> = aobs.iter().map(|(aob, disp_offset, instr_len)| (*aob, Signature::new(aob).map(|s| s.rip(*disp_offset, *instr_len)))).collect();
+
+        let mut attempts: Vec<DumpError> = Vec::new();
+
+        for (idx, (aob, sig)) in compiled.iter().enumerate() {
             println!("Scanning for {} (AOB {})...", target_name, idx);
-            match Scanner::scan(&process.memory, process.main_module_base, process.main_module_base + process.main_module_size, aob) {
+            let sig = match sig {
+                Ok(s) => s,
+                Err(reason) => {
+                    let e = DumpError::InvalidSignature { aob_index: idx, reason: reason.clone() };
+                    println!("  -> {}", e);
+                    attempts.push(e);
+                    continue;
+                }
+            };
+            match sig.raw_matches(process, process.main_module_base, process.main_module_base + process.main_module_size) {
                 Ok(results) => {
                     if results.is_empty() {
-                        println!("  -> AOB {} failed: Signature not found in memory.", idx);
+                        let e = DumpError::SignatureNotFound { aob_index: idx };
+                        println!("  -> {}", e);
+                        attempts.push(e);
                         continue;
                     }
 
                     for &addr in &results {
-                        match Self::resolve_rip(process, addr, *disp_offset, *instr_len) {
+                        match sig.resolve_at(process, addr) {
                             Ok(resolved) => {
                                 // Quick heuristic to validate if it's a valid pointer within user space
                                 if resolved > 0x10000 && resolved < 0x7FFFFFFFFFFF {
                                     println!("  -> Found {} at 0x{:X} [{}]", target_name, resolved, aob);
                                     return Ok(resolved);
                                 } else {
-                                    println!("  -> AOB {} failed at 0x{:X}: Resolved address (0x{:X}) is out of valid user-space memory bounds.", idx, addr, resolved);
+                                    let e = DumpError::ResolvedOutOfBounds { aob_index: idx, address: addr, resolved };
+                                    println!("  -> {}", e);
+                                    attempts.push(e);
                                 }
                             }
-                            Err(e) => {
-                                println!("  -> AOB {} failed at 0x{:X}: Could not read displacement. Error: {}", idx, addr, e);
+                            Err(reason) => {
+                                let e = DumpError::ReadFailed { aob_index: idx, address: addr, reason };
+                                println!("  -> {}", e);
+                                attempts.push(e);
                             }
                         }
                     }
                 }
-                Err(e) => {
-                    println!("  -> AOB {} failed during scanning pipeline: {}", idx, e);
+                Err(reason) => {
+                    let e = DumpError::ScanFailed { aob_index: idx, reason };
+                    println!("  -> {}", e);
+                    attempts.push(e);
                 }
             }
         }
 
-        Err(format!("Could not find {} with any of the known AOB signatures", target_name))
+        Err(DumpError::AllSignaturesFailed { target_name: target_name.to_string(), attempts })
     }
 }
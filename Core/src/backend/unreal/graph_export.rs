@@ -0,0 +1,143 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+use std::collections::{HashSet, VecDeque};
+
+/// Builds a Graphviz DOT document from the class hierarchy (SuperStruct chain) and object
+/// graph (Outer containment) this crate already resolves. The inheritance edges are read live
+/// (SuperStruct isn't cached on `ObjectData`), while containment just reuses the cache.
+///
+/// When `root_package` is set, only classes/structs whose `full_name` starts with that prefix
+/// are emitted, so large games don't produce an unusable blob.
+pub fn export_class_graph(obj_mgr: &ObjectManager, process: &Process, offsets: &UEOffset, root_package: Option<&str>) -> String {
+    let mut nodes: HashSet<String> = HashSet::new();
+    let mut inheritance_edges: HashSet<(String, String)> = HashSet::new();
+    let mut outer_edges: HashSet<(String, String)> = HashSet::new();
+
+    for entry in obj_mgr.cache_by_address.iter() {
+        let obj = entry.value();
+        let type_lower = obj.type_name.to_lowercase();
+        if !(type_lower.contains("class") || type_lower.contains("struct")) || type_lower.contains("function") {
+            continue;
+        }
+        if let Some(prefix) = root_package {
+            if !obj.full_name.starts_with(prefix) {
+                continue;
+            }
+        }
+
+        nodes.insert(obj.name.clone());
+
+        // Inheritance edge: follow SuperStruct to the parent class/struct.
+        let super_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.super_struct)).unwrap_or(0);
+        if super_addr > 0x10000 {
+            if let Some(parent) = obj_mgr.cache_by_address.get(&super_addr) {
+                if !parent.name.is_empty() {
+                    inheritance_edges.insert((obj.name.clone(), parent.name.clone()));
+                }
+            }
+        }
+
+        // Outer containment edge (e.g. a struct nested inside its owning package/class).
+        if obj.outer > 0x10000 {
+            if let Some(outer) = obj_mgr.cache_by_address.get(&obj.outer) {
+                if !outer.name.is_empty() {
+                    outer_edges.insert((obj.name.clone(), outer.name.clone()));
+                }
+            }
+        }
+    }
+
+    let mut dot = String::new();
+    dot.push_str("digraph UE {\n  rankdir=LR;\n");
+
+    for node in &nodes {
+        dot.push_str(&format!("  \"{}\";\n", escape(node)));
+    }
+    for (child, parent) in &inheritance_edges {
+        dot.push_str(&format!("  \"{}\" -> \"{}\";\n", escape(child), escape(parent)));
+    }
+    for (child, outer) in &outer_edges {
+        dot.push_str(&format!("  \"{}\" -> \"{}\" [style=dashed, color=gray];\n", escape(child), escape(outer)));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Builds a Graphviz DOT document of a single object's reflection hierarchy, instead of
+/// `export_class_graph`'s whole-cache sweep: starting at `root_address`, follows Outer (the
+/// pointer `ObjectManager::resolve_full_name` chases to flatten a `full_name`), Class and the
+/// class's own SuperStruct one hop at a time, breadth-first, so users
+/// get a visual map of how one object relates to its container/class/ancestors instead of a
+/// single dotted string.
+///
+/// Nodes are deduplicated by memory address (used as the DOT node id, so a class shared by many
+/// instances collapses to one node); labels are the resolved FName, escaped for DOT. Traversal
+/// stops past `depth` hops from the root and never follows a null/low pointer (`<= 0x10000`),
+/// mirroring the existing outer-chain walk's own cap.
+pub fn export_object_graph(obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset, root_address: usize, depth: usize) -> String {
+    let mut labels: Vec<(usize, String)> = Vec::new();
+    let mut edges: Vec<(usize, usize, &'static str)> = Vec::new();
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+
+    if root_address > 0x10000 {
+        queue.push_back((root_address, 0));
+        visited.insert(root_address);
+    }
+
+    while let Some((address, hops)) = queue.pop_front() {
+        let name = obj_mgr
+            .try_save_object(address, process, name_pool, offsets, 0, 5)
+            .map(|obj| obj.name)
+            .unwrap_or_else(|| format!("0x{:X}", address));
+        labels.push((address, name));
+
+        if hops >= depth {
+            continue;
+        }
+
+        let outer = process.memory.try_read_pointer(address.wrapping_add(offsets.outer)).unwrap_or(0);
+        if outer > 0x10000 {
+            edges.push((address, outer, "outer"));
+            if visited.insert(outer) {
+                queue.push_back((outer, hops + 1));
+            }
+        }
+
+        let class_ptr = process.memory.try_read_pointer(address.wrapping_add(offsets.class)).unwrap_or(0);
+        if class_ptr > 0x10000 {
+            edges.push((address, class_ptr, "class"));
+            if visited.insert(class_ptr) {
+                queue.push_back((class_ptr, hops + 1));
+            }
+
+            let super_ptr = process.memory.try_read_pointer(class_ptr.wrapping_add(offsets.super_struct)).unwrap_or(0);
+            if super_ptr > 0x10000 {
+                edges.push((class_ptr, super_ptr, "super"));
+                if visited.insert(super_ptr) {
+                    queue.push_back((super_ptr, hops + 1));
+                }
+            }
+        }
+    }
+
+    let mut dot = String::new();
+    dot.push_str("digraph UEObjectGraph {\n  rankdir=LR;\n");
+
+    for (address, name) in &labels {
+        dot.push_str(&format!("  \"0x{:X}\" [label=\"{}\"];\n", address, escape(name)));
+    }
+    for (from, to, label) in &edges {
+        dot.push_str(&format!("  \"0x{:X}\" -> \"0x{:X}\" [label=\"{}\"];\n", from, to, label));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
@@ -0,0 +1,333 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+
+/// One decoded Kismet bytecode instruction: the byte this opcode started at (useful for
+/// correlating against `EX_Jump`/`EX_Context`'s skip offsets, which are byte offsets into this
+/// same stream), its `EExprToken` mnemonic, and a flattened text rendering of its operands
+/// (including any recursively-decoded sub-expressions, inlined as `[Mnemonic operands]`).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DisassembledInstruction {
+    pub byte_offset: usize,
+    pub mnemonic: String,
+    pub operands: String,
+}
+
+const EX_END_OF_SCRIPT: u8 = 0x53;
+const EX_END_FUNCTION_PARMS: u8 = 0x16;
+const EX_END_ARRAY: u8 = 0x32;
+
+/// Caps total decoded instructions, independent of the script's own length — a corrupt/partial
+/// read could otherwise desync the cursor and "succeed" at decoding garbage indefinitely.
+const MAX_INSTRUCTIONS: usize = 20_000;
+/// Caps how deep `decode_expr` recurses into sub-expressions (`EX_Context`'s object/result,
+/// a call's parameter list, ...) — a malformed stream could otherwise recurse until the stack
+/// overflows instead of just producing a bad disassembly.
+const MAX_RECURSION_DEPTH: usize = 64;
+/// Caps how many parameters/elements a single call or array/set/map literal will read before
+/// giving up on finding its terminator — same "don't loop forever on a malformed stream" reason
+/// as `MAX_INSTRUCTIONS`.
+const MAX_SUBEXPR_LIST: usize = 1024;
+
+/// Reads a `UFunction`'s `Script` bytecode (`offsets.script`'s `TArray<uint8>`) and decodes it
+/// one `EExprToken` opcode at a time into a readable instruction listing — analogous to a VM
+/// disassembler walking a flat byte buffer. Stops at `EX_EndOfScript`, when the cursor reaches
+/// the script's length, when a read runs past the end of the buffer, or after
+/// `MAX_INSTRUCTIONS`/an unrecognized opcode — whichever comes first; an instruction noting the
+/// stop reason is appended in the two latter cases rather than silently truncating.
+pub fn get_function_disassembly(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, function_address: usize) -> Result<Vec<DisassembledInstruction>, String> {
+    let script_addr = function_address.wrapping_add(offsets.script);
+    let data_ptr = proc.memory.try_read_pointer(script_addr).unwrap_or(0);
+    let num = proc.memory.try_read::<i32>(script_addr.wrapping_add(0x8)).unwrap_or(0);
+    if data_ptr <= 0x10000 || num <= 0 {
+        return Ok(Vec::new());
+    }
+
+    let length = (num as usize).min(1 << 20); // 1 MiB: far beyond any real UFunction's bytecode
+    let bytes = proc.memory.read_bytes(data_ptr, length).map_err(|e| format!("Failed to read Script bytes: {}", e))?;
+
+    let mut cursor = Cursor { bytes: &bytes, pos: 0 };
+    let mut instructions = Vec::new();
+
+    while cursor.pos < bytes.len() {
+        let byte_offset = cursor.pos;
+        if instructions.len() >= MAX_INSTRUCTIONS {
+            instructions.push(DisassembledInstruction { byte_offset, mnemonic: "<stopped>".to_string(), operands: "max instruction count reached".to_string() });
+            break;
+        }
+
+        let Some(opcode) = cursor.peek_u8() else { break };
+        if opcode == EX_END_OF_SCRIPT {
+            cursor.read_u8();
+            instructions.push(DisassembledInstruction { byte_offset, mnemonic: "EX_EndOfScript".to_string(), operands: String::new() });
+            break;
+        }
+
+        match decode_expr(proc, obj_mgr, name_pool, offsets, &mut cursor, 0) {
+            Ok((mnemonic, operands)) => instructions.push(DisassembledInstruction { byte_offset, mnemonic, operands }),
+            Err(reason) => {
+                instructions.push(DisassembledInstruction { byte_offset, mnemonic: "<stopped>".to_string(), operands: reason });
+                break;
+            }
+        }
+    }
+
+    Ok(instructions)
+}
+
+/// Decodes a single `EExprToken` starting at the cursor's current position, recursing into
+/// sub-expressions (an `EX_Context`'s object/result, a call's parameter list, ...) as needed.
+/// Returns `Err` on a truncated read, an opcode this disassembler doesn't recognize, or
+/// `MAX_RECURSION_DEPTH` exceeded — any of which means the cursor can no longer be trusted to be
+/// aligned on a real instruction boundary, so the caller stops rather than guessing forward.
+fn decode_expr(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, cursor: &mut Cursor, depth: usize) -> Result<(String, String), String> {
+    if depth > MAX_RECURSION_DEPTH {
+        return Err("max recursion depth exceeded".to_string());
+    }
+
+    let opcode = cursor.read_u8().ok_or("truncated opcode")?;
+    let resolve = |ptr: usize| resolve_object_name(proc, obj_mgr, name_pool, offsets, ptr);
+
+    match opcode {
+        // ── no-operand opcodes ──
+        0x0B => Ok(("EX_Nothing".to_string(), String::new())),
+        0x15 => Ok(("EX_EndParmValue".to_string(), String::new())),
+        0x17 => Ok(("EX_Self".to_string(), String::new())),
+        0x25 => Ok(("EX_IntZero".to_string(), String::new())),
+        0x26 => Ok(("EX_IntOne".to_string(), String::new())),
+        0x27 => Ok(("EX_True".to_string(), String::new())),
+        0x28 => Ok(("EX_False".to_string(), String::new())),
+        0x2A => Ok(("EX_NoObject".to_string(), String::new())),
+        0x2D => Ok(("EX_NoInterface".to_string(), String::new())),
+        0x4F => Ok(("EX_Breakpoint".to_string(), String::new())),
+        0x5A => Ok(("EX_WireTracepoint".to_string(), String::new())),
+        0x5E => Ok(("EX_Tracepoint".to_string(), String::new())),
+
+        // ── literals ──
+        0x1D => Ok(("EX_IntConst".to_string(), cursor.read_i32().ok_or("truncated i32")?.to_string())),
+        0x1E => Ok(("EX_FloatConst".to_string(), cursor.read_f32().ok_or("truncated f32")?.to_string())),
+        0x37 => Ok(("EX_DoubleConst".to_string(), cursor.read_f64().ok_or("truncated f64")?.to_string())),
+        0x35 => Ok(("EX_Int64Const".to_string(), cursor.read_i64().ok_or("truncated i64")?.to_string())),
+        0x36 => Ok(("EX_UInt64Const".to_string(), cursor.read_u64().ok_or("truncated u64")?.to_string())),
+        0x24 => Ok(("EX_ByteConst".to_string(), cursor.read_u8().ok_or("truncated byte")?.to_string())),
+        0x2C => Ok(("EX_IntConstByte".to_string(), cursor.read_u8().ok_or("truncated byte")?.to_string())),
+        0x1F => Ok(("EX_StringConst".to_string(), cursor.read_ansi_string().ok_or("truncated string")?)),
+        0x34 => Ok(("EX_UnicodeStringConst".to_string(), cursor.read_unicode_string().ok_or("truncated string")?)),
+        0x21 => {
+            let id = cursor.read_i32().ok_or("truncated FName index")?;
+            let _number = cursor.read_i32().ok_or("truncated FName number")?;
+            Ok(("EX_NameConst".to_string(), name_pool.get_name(proc, id as u32).unwrap_or_default()))
+        }
+        0x20 | 0x67 => {
+            let ptr = cursor.read_ptr().ok_or("truncated object pointer")?;
+            Ok((if opcode == 0x20 { "EX_ObjectConst" } else { "EX_SoftObjectConst" }.to_string(), resolve(ptr)))
+        }
+        0x33 => {
+            let ptr = cursor.read_ptr().ok_or("truncated property pointer")?;
+            Ok(("EX_PropertyConst".to_string(), resolve(ptr)))
+        }
+
+        // ── variable reads (8-byte FProperty* resolved via the object cache) ──
+        0x00 | 0x01 | 0x02 | 0x48 | 0x6C => {
+            let mnemonic = match opcode {
+                0x00 => "EX_LocalVariable",
+                0x01 => "EX_InstanceVariable",
+                0x02 => "EX_DefaultVariable",
+                0x48 => "EX_LocalOutVariable",
+                _ => "EX_ClassSparseDataVariable",
+            };
+            let ptr = cursor.read_ptr().ok_or("truncated property pointer")?;
+            Ok((mnemonic.to_string(), resolve(ptr)))
+        }
+
+        // ── control flow ──
+        0x06 => Ok(("EX_Jump".to_string(), format!("-> 0x{:X}", cursor.read_u32().ok_or("truncated offset")?))),
+        0x07 => {
+            let target = cursor.read_u32().ok_or("truncated offset")?;
+            let (cm, co) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            Ok(("EX_JumpIfNot".to_string(), format!("-> 0x{:X} if !({} {})", target, cm, co)))
+        }
+        0x4B => Ok(("EX_PushExecutionFlow".to_string(), format!("-> 0x{:X}", cursor.read_u32().ok_or("truncated offset")?))),
+        0x4C => Ok(("EX_PopExecutionFlow".to_string(), String::new())),
+        0x4E => {
+            let (cm, co) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            Ok(("EX_PopExecutionFlowIfNot".to_string(), format!("if !({} {})", cm, co)))
+        }
+        0x04 => {
+            let (rm, ro) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            Ok(("EX_Return".to_string(), format!("[{} {}]", rm, ro)))
+        }
+
+        // ── assignment ──
+        0x0F | 0x14 | 0x5F | 0x60 | 0x43 | 0x44 => {
+            let mnemonic = match opcode {
+                0x0F => "EX_Let",
+                0x14 => "EX_LetBool",
+                0x5F => "EX_LetObj",
+                0x60 => "EX_LetWeakObjPtr",
+                0x43 => "EX_LetMulticastDelegate",
+                _ => "EX_LetDelegate",
+            };
+            if opcode == 0x0F {
+                cursor.read_ptr().ok_or("truncated property pointer")?; // the LHS property, unused in the rendered text below
+            }
+            let (vm, vo) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            let (em, eo) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            Ok((mnemonic.to_string(), format!("[{} {}] = [{} {}]", vm, vo, em, eo)))
+        }
+
+        // ── function calls ──
+        0x1B | 0x1C | 0x45 | 0x46 | 0x68 => {
+            let mnemonic = match opcode {
+                0x1B => "EX_VirtualFunction",
+                0x1C => "EX_FinalFunction",
+                0x45 => "EX_LocalVirtualFunction",
+                0x46 => "EX_LocalFinalFunction",
+                _ => "EX_CallMath",
+            };
+            let target = if opcode == 0x1B || opcode == 0x45 {
+                let id = cursor.read_i32().ok_or("truncated FName index")?;
+                let _number = cursor.read_i32().ok_or("truncated FName number")?;
+                name_pool.get_name(proc, id as u32).unwrap_or_default()
+            } else {
+                resolve(cursor.read_ptr().ok_or("truncated function pointer")?)
+            };
+
+            let mut params = Vec::new();
+            while cursor.peek_u8() != Some(EX_END_FUNCTION_PARMS) && params.len() < MAX_SUBEXPR_LIST {
+                let (pm, po) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+                params.push(format!("{} {}", pm, po));
+            }
+            cursor.read_u8(); // consume EX_EndFunctionParms
+
+            Ok((mnemonic.to_string(), format!("{}({})", target, params.join(", "))))
+        }
+
+        // ── member access ──
+        0x19 | 0x1A => {
+            let (om, oo) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            let skip = cursor.read_u32().ok_or("truncated skip offset")?;
+            let value_ptr = cursor.read_ptr().ok_or("truncated property pointer")?;
+            let (rm, ro) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            let mnemonic = if opcode == 0x19 { "EX_Context" } else { "EX_Context_FailSilent" };
+            Ok((mnemonic.to_string(), format!("object=[{} {}], skip=0x{:X}, value_prop={}, result=[{} {}]", om, oo, skip, resolve(value_ptr), rm, ro)))
+        }
+        0x42 => {
+            let ptr = cursor.read_ptr().ok_or("truncated property pointer")?;
+            let (em, eo) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            Ok(("EX_StructMemberContext".to_string(), format!("{}.[{} {}]", resolve(ptr), em, eo)))
+        }
+
+        // ── casts ──
+        0x13 | 0x2E | 0x38 | 0x51 | 0x54 | 0x55 => {
+            let mnemonic = match opcode {
+                0x13 => "EX_MetaCast",
+                0x2E => "EX_DynamicCast",
+                0x38 => "EX_Cast",
+                0x51 => "EX_ObjToInterfaceCast",
+                0x54 => "EX_CrossInterfaceCast",
+                _ => "EX_InterfaceToObjCast",
+            };
+            let class_ptr = cursor.read_ptr().ok_or("truncated class pointer")?;
+            let (em, eo) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+            Ok((mnemonic.to_string(), format!("({}) [{} {}]", resolve(class_ptr), em, eo)))
+        }
+
+        // ── array literal ──
+        0x31 => {
+            let ptr = cursor.read_ptr().ok_or("truncated property pointer")?;
+            let mut elements = Vec::new();
+            while cursor.peek_u8() != Some(EX_END_ARRAY) && elements.len() < MAX_SUBEXPR_LIST {
+                let (em, eo) = decode_expr(proc, obj_mgr, name_pool, offsets, cursor, depth + 1)?;
+                elements.push(format!("{} {}", em, eo));
+            }
+            cursor.read_u8(); // consume EX_EndArray
+            Ok(("EX_SetArray".to_string(), format!("{} = [{}]", resolve(ptr), elements.join(", "))))
+        }
+
+        _ => Err(format!("unrecognized opcode 0x{:02X}", opcode)),
+    }
+}
+
+/// Resolves an 8-byte `UObject*`/`FProperty*` operand to its `ObjectManager`-cached name, the
+/// same cache/`FNameIndex` fallback `get_instance_details` uses for object-typed properties —
+/// falling back to the raw pointer when the cache has nothing, and to `"None"` for a null pointer.
+fn resolve_object_name(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, ptr: usize) -> String {
+    if ptr <= 0x10000 {
+        return "None".to_string();
+    }
+    obj_mgr.try_save_object(ptr, proc, name_pool, offsets, 0, 5).map(|o| o.name).unwrap_or_else(|| format!("0x{:X}", ptr))
+}
+
+/// A little-endian cursor over the `Script` byte buffer; every fixed-width `read_*` advances
+/// `pos` only on success, while the string readers advance as they scan, so an unterminated
+/// string leaves `pos` at the end of the buffer.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek_u8(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        let v = self.bytes.get(self.pos).copied()?;
+        self.pos += 1;
+        Some(v)
+    }
+
+    fn read_n(&mut self, n: usize) -> Option<&'a [u8]> {
+        let slice = self.bytes.get(self.pos..self.pos + n)?;
+        self.pos += n;
+        Some(slice)
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.read_n(4)?.try_into().ok()?))
+    }
+
+    fn read_i32(&mut self) -> Option<i32> {
+        Some(i32::from_le_bytes(self.read_n(4)?.try_into().ok()?))
+    }
+
+    fn read_u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.read_n(8)?.try_into().ok()?))
+    }
+
+    fn read_i64(&mut self) -> Option<i64> {
+        Some(i64::from_le_bytes(self.read_n(8)?.try_into().ok()?))
+    }
+
+    fn read_f32(&mut self) -> Option<f32> {
+        Some(f32::from_le_bytes(self.read_n(4)?.try_into().ok()?))
+    }
+
+    fn read_f64(&mut self) -> Option<f64> {
+        Some(f64::from_le_bytes(self.read_n(8)?.try_into().ok()?))
+    }
+
+    fn read_ptr(&mut self) -> Option<usize> {
+        Some(self.read_u64()? as usize)
+    }
+
+    fn read_ansi_string(&mut self) -> Option<String> {
+        let start = self.pos;
+        while self.read_u8()? != 0 {}
+        Some(String::from_utf8_lossy(&self.bytes[start..self.pos - 1]).to_string())
+    }
+
+    fn read_unicode_string(&mut self) -> Option<String> {
+        let mut wide = Vec::new();
+        loop {
+            let unit = u16::from_le_bytes(self.read_n(2)?.try_into().ok()?);
+            if unit == 0 {
+                break;
+            }
+            wide.push(unit);
+        }
+        Some(String::from_utf16_lossy(&wide))
+    }
+}
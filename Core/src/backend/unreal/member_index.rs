@@ -0,0 +1,97 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+use dashmap::DashMap;
+
+/// One-time, in-memory index of every class/struct member, built after attach so
+/// `global_search`'s Member mode no longer has to re-walk the ChildProperty linked list and
+/// re-read every member's FName out of process memory on every keystroke.
+pub struct MemberIndex {
+    /// class_address -> (member_name_id, member_offset) for every member on that class/struct.
+    pub members_by_class: DashMap<usize, Vec<(u32, usize)>>,
+    /// Interned member-name table: member_name_id -> resolved String, deduplicated so repeated
+    /// names (e.g. "Value", "Index") only ever take one allocation.
+    pub names: DashMap<u32, String>,
+}
+
+impl MemberIndex {
+    pub fn new() -> Self {
+        Self { members_by_class: DashMap::new(), names: DashMap::new() }
+    }
+
+    /// Walks the member chain of every cached class/struct exactly once, interning member
+    /// names as it goes. Returns the total number of (class, member) pairs indexed.
+    pub fn build(&self, obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset) -> usize {
+        self.members_by_class.clear();
+        self.names.clear();
+
+        let mut total = 0;
+
+        for entry in obj_mgr.cache_by_address.iter() {
+            let obj = entry.value();
+            let type_lower = obj.type_name.to_lowercase();
+            if !(type_lower.contains("class") || type_lower.contains("struct")) {
+                continue;
+            }
+
+            let mut members = Vec::new();
+            let mut child_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.member)).unwrap_or(0);
+            let mut safety = 0;
+
+            while child_addr > 0x10000 && safety < 2000 {
+                safety += 1;
+
+                let member_name_id = process.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0) as u32;
+                let member_offset = process.memory.try_read::<i32>(child_addr.wrapping_add(offsets.offset)).unwrap_or(0) as usize;
+
+                if !self.names.contains_key(&member_name_id) {
+                    if let Ok(name) = name_pool.get_name(process, member_name_id) {
+                        if !name.is_empty() {
+                            self.names.insert(member_name_id, name);
+                        }
+                    }
+                }
+
+                if self.names.contains_key(&member_name_id) {
+                    members.push((member_name_id, member_offset));
+                    total += 1;
+                }
+
+                child_addr = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+            }
+
+            if !members.is_empty() {
+                self.members_by_class.insert(obj.address, members);
+            }
+        }
+
+        total
+    }
+
+    /// Drops all indexed data, forcing the next search to rebuild before it can query again.
+    pub fn invalidate(&self) {
+        self.members_by_class.clear();
+        self.names.clear();
+    }
+
+    pub fn is_built(&self) -> bool {
+        !self.members_by_class.is_empty()
+    }
+
+    /// Looks up every (class_address, member_name) pair whose interned name contains `query_lower`.
+    pub fn search(&self, query_lower: &str) -> Vec<(usize, String)> {
+        let mut results = Vec::new();
+        for entry in self.members_by_class.iter() {
+            let class_address = *entry.key();
+            for &(name_id, _offset) in entry.value() {
+                if let Some(name) = self.names.get(&name_id) {
+                    if name.to_lowercase().contains(query_lower) {
+                        results.push((class_address, name.clone()));
+                    }
+                }
+            }
+        }
+        results
+    }
+}
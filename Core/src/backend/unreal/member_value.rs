@@ -0,0 +1,240 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+
+/// A decoded live value for a single UProperty-backed class member.
+/// Unlike the name-only member walk in `global_search`, this actually reads and
+/// interprets the bytes at `instance_addr + member.offset` according to the
+/// property's type name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Byte(u8),
+    Name(String),
+    ObjectPtr(usize),
+    Str(String),
+    Bytes(Vec<u8>),
+    Unknown,
+}
+
+impl std::fmt::Display for PropertyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyValue::Bool(v) => write!(f, "{}", v),
+            PropertyValue::Int(v) => write!(f, "{}", v),
+            PropertyValue::UInt(v) => write!(f, "{}", v),
+            PropertyValue::Float(v) => write!(f, "{}", v),
+            PropertyValue::Byte(v) => write!(f, "{}", v),
+            PropertyValue::Name(v) => write!(f, "{}", v),
+            PropertyValue::ObjectPtr(v) => write!(f, "0x{:X}", v),
+            PropertyValue::Str(v) => write!(f, "{}", v),
+            PropertyValue::Bytes(v) => write!(f, "{}", v.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")),
+            PropertyValue::Unknown => write!(f, "<unknown>"),
+        }
+    }
+}
+
+/// Coarse classification of a property's *decode strategy*, independent of the exact UE
+/// property-class name. `read_member_value` dispatches on this instead of re-matching the
+/// type string at every call site, so adding a new decodable kind only means adding one
+/// match arm here and one branch in `FromStr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyKind {
+    Boolean,
+    Integer,
+    Float,
+    Byte,
+    Name,
+    Str,
+    ObjectRef,
+    /// Embedded struct data (StructProperty). The value at `instance_addr + offset` isn't a
+    /// pointer to dereference — it's the struct's own address, which is itself the jump
+    /// target for a follow-up `read_instance_values(addr, sub_type_address)` call.
+    Struct,
+    /// No typed decoder for this property kind (ArrayProperty, MapProperty, SetProperty, ...)
+    /// — the caller reads `prop_size` raw bytes instead of guessing at a layout.
+    Bytes,
+}
+
+impl std::str::FromStr for PropertyKind {
+    type Err = std::convert::Infallible;
+
+    /// Never fails: anything that isn't a recognized property-class name classifies as
+    /// `Bytes`, which `read_member_value` returns as raw bytes instead of a typed value.
+    fn from_str(property_type: &str) -> Result<Self, Self::Err> {
+        let t = property_type.to_lowercase();
+        Ok(if t.contains("boolproperty") {
+            PropertyKind::Boolean
+        } else if t.contains("nameproperty") {
+            PropertyKind::Name
+        } else if t.contains("strproperty") {
+            PropertyKind::Str
+        } else if t.contains("structproperty") {
+            PropertyKind::Struct
+        } else if t.contains("objectproperty") || t.contains("classproperty") || t.contains("softobjectproperty") || t.contains("weakobjectproperty") || t.contains("interfaceproperty") {
+            PropertyKind::ObjectRef
+        } else if t.contains("doubleproperty") || t.contains("floatproperty") {
+            PropertyKind::Float
+        } else if t.contains("byteproperty") {
+            PropertyKind::Byte
+        } else if t.contains("int") {
+            PropertyKind::Integer
+        } else {
+            PropertyKind::Bytes
+        })
+    }
+}
+
+/// Descriptor for a single class member, gathered from the ChildProperty linked list
+/// (name + type via the FNamePool, plus the `offset`/`prop_size`/`bit_mask` fields that
+/// `UEOffset` already exposes but that most callers only use to print, not to read).
+#[derive(Debug, Clone)]
+pub struct MemberDescriptor {
+    pub name: String,
+    pub type_name: String,
+    pub offset: usize,
+    pub prop_size: i32,
+    pub bit_mask: u8,
+}
+
+/// Reads `instance_addr + member.offset` out of the target process and decodes it according
+/// to `member.type_name`'s `PropertyKind`. Property kinds without a typed decoder (e.g.
+/// ArrayProperty, MapProperty) come back as their `prop_size` raw bytes (`PropertyValue::Bytes`)
+/// rather than a guessed layout, or as `PropertyValue::Unknown` when that size is outside
+/// 1..=4096 or the read fails.
+pub fn read_member_value(process: &Process, name_pool: &FNamePool, obj_mgr: &ObjectManager, offsets: &UEOffset, instance_addr: usize, member: &MemberDescriptor) -> PropertyValue {
+    let addr = instance_addr.wrapping_add(member.offset);
+    let type_lower = member.type_name.to_lowercase();
+    let kind: PropertyKind = member.type_name.parse().unwrap();
+
+    match kind {
+        PropertyKind::Boolean => {
+            let byte = process.memory.try_read::<u8>(addr).unwrap_or(0);
+            let bit = if member.bit_mask > 0 { member.bit_mask } else { 1 };
+            PropertyValue::Bool((byte & bit) != 0)
+        }
+
+        PropertyKind::Integer => {
+            if type_lower.contains("int64property") {
+                match process.memory.try_read::<i64>(addr) {
+                    Some(v) => PropertyValue::Int(v),
+                    None => PropertyValue::Unknown,
+                }
+            } else if type_lower.contains("uint64property") {
+                match process.memory.try_read::<u64>(addr) {
+                    Some(v) => PropertyValue::UInt(v),
+                    None => PropertyValue::Unknown,
+                }
+            } else if type_lower.contains("uintproperty") || type_lower.contains("uint32property") || type_lower.contains("int16property") || type_lower.contains("uint16property") {
+                match read_sized_unsigned(process, addr, member.prop_size) {
+                    Some(v) => PropertyValue::UInt(v),
+                    None => PropertyValue::Unknown,
+                }
+            } else {
+                match read_sized_signed(process, addr, member.prop_size) {
+                    Some(v) => PropertyValue::Int(v),
+                    None => PropertyValue::Unknown,
+                }
+            }
+        }
+
+        PropertyKind::Float => {
+            if type_lower.contains("doubleproperty") {
+                match process.memory.try_read::<f64>(addr) {
+                    Some(v) => PropertyValue::Float(v),
+                    None => PropertyValue::Unknown,
+                }
+            } else {
+                match process.memory.try_read::<f32>(addr) {
+                    Some(v) => PropertyValue::Float(v as f64),
+                    None => PropertyValue::Unknown,
+                }
+            }
+        }
+
+        PropertyKind::Byte => match process.memory.try_read::<u8>(addr) {
+            Some(v) => PropertyValue::Byte(v),
+            None => PropertyValue::Unknown,
+        },
+
+        PropertyKind::ObjectRef => match process.memory.try_read_pointer(addr) {
+            Some(ptr) if ptr > 0x10000 => {
+                // Resolving through the object manager populates the cache for later lookups
+                // (e.g. the inspector hierarchy), matching how other commands already do it.
+                obj_mgr.try_save_object(ptr, process, name_pool, offsets, 0, 5);
+                PropertyValue::ObjectPtr(ptr)
+            }
+            Some(_) => PropertyValue::ObjectPtr(0),
+            None => PropertyValue::Unknown,
+        },
+
+        // Embedded, not a real pointer — `addr` itself is the struct's start and the jump
+        // target for a follow-up read_instance_values(addr, sub_type_address) call.
+        PropertyKind::Struct => PropertyValue::ObjectPtr(addr),
+
+        PropertyKind::Name => {
+            let name_id = process.memory.try_read::<i32>(addr).unwrap_or(0);
+            PropertyValue::Name(name_pool.get_name(process, name_id as u32).unwrap_or_default())
+        }
+
+        PropertyKind::Str => {
+            // FString is a TArray<TCHAR>: data pointer, count, max at +0x0/+0x8/+0xC.
+            let data_ptr = process.memory.try_read_pointer(addr).unwrap_or(0);
+            let count = process.memory.try_read::<i32>(addr.wrapping_add(0x8)).unwrap_or(0);
+            if data_ptr > 0x10000 && count > 1 && count < 2048 {
+                let byte_len = (count as usize - 1) * 2; // exclude the null terminator, UTF-16 (wide) chars
+                if let Ok(bytes) = process.memory.read_bytes(data_ptr, byte_len) {
+                    let wide: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+                    return PropertyValue::Str(String::from_utf16_lossy(&wide));
+                }
+            }
+            PropertyValue::Str(String::new())
+        }
+
+        PropertyKind::Bytes => match member.prop_size {
+            n if n > 0 && n <= 4096 => match process.memory.read_bytes(addr, n as usize) {
+                Ok(bytes) => PropertyValue::Bytes(bytes),
+                Err(_) => PropertyValue::Unknown,
+            },
+            _ => PropertyValue::Unknown,
+        },
+    }
+}
+
+/// Reads `member.prop_size` raw bytes at `instance_addr + member.offset` and formats them as a
+/// space-separated hex string, independent of `read_member_value`'s typed decode — so a caller
+/// can show "what's actually there" alongside the interpreted value even when the decoder guessed
+/// wrong (or the property kind has no decoder at all). Empty string if the size is unusable or
+/// the read fails, matching `read_member_value`'s own "don't guess" fallback.
+pub fn raw_hex(process: &Process, instance_addr: usize, member: &MemberDescriptor) -> String {
+    let addr = instance_addr.wrapping_add(member.offset);
+    if member.prop_size <= 0 || member.prop_size > 4096 {
+        return String::new();
+    }
+    match process.memory.read_bytes(addr, member.prop_size as usize) {
+        Ok(bytes) => bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" "),
+        Err(_) => String::new(),
+    }
+}
+
+fn read_sized_signed(process: &Process, addr: usize, prop_size: i32) -> Option<i64> {
+    match prop_size {
+        1 => process.memory.try_read::<i8>(addr).map(|v| v as i64),
+        2 => process.memory.try_read::<i16>(addr).map(|v| v as i64),
+        8 => process.memory.try_read::<i64>(addr).map(|v| v as i64),
+        _ => process.memory.try_read::<i32>(addr).map(|v| v as i64),
+    }
+}
+
+fn read_sized_unsigned(process: &Process, addr: usize, prop_size: i32) -> Option<u64> {
+    match prop_size {
+        1 => process.memory.try_read::<u8>(addr).map(|v| v as u64),
+        2 => process.memory.try_read::<u16>(addr).map(|v| v as u64),
+        8 => process.memory.try_read::<u64>(addr).map(|v| v as u64),
+        _ => process.memory.try_read::<u32>(addr).map(|v| v as u64),
+    }
+}
@@ -1,11 +1,30 @@
 use crate::backend::os::process::Process;
+use crate::backend::unreal::offsets::UEOffset;
+use dashmap::DashMap;
 use rayon::prelude::*;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use tauri::Emitter;
 
 pub struct FNamePool {
     base_address: usize,
     string_offset: AtomicUsize,
+    /// Byte offset to the pool's first block-pointer entry (`UEOffset::fname_pool_entry_base`).
+    entry_base: usize,
+    /// Bytes per name-entry unit within a block (`UEOffset::fname_pool_stride`).
+    stride: usize,
+    /// Whether ids split into `(block, offset)` via `id >> 16`/`id & 0xFFFF`, or address a flat
+    /// single block directly (`UEOffset::fname_pool_is_chunked`).
+    is_chunked: bool,
+    /// Whether entries store UTF-16LE instead of ANSI/UTF-8 (`UEOffset::wide_strings`).
+    wide_strings: bool,
+    /// Concurrent atom table: every id `get_name` has successfully resolved, keyed both ways so
+    /// repeat lookups (forward) and name searches (reverse) never touch process memory again.
+    /// Lives on the pool itself rather than in `AppState` so a freshly re-parsed pool (a new
+    /// `FNamePool` instance, see `parse_guobject_array`) starts with empty maps for free — there
+    /// is no separate "clear the cache" step to remember.
+    atoms_by_id: DashMap<u32, Arc<str>>,
+    ids_by_name: DashMap<Arc<str>, Vec<u32>>,
 }
 
 #[derive(Clone, serde::Serialize)]
@@ -17,20 +36,46 @@ struct ProgressPayload {
 }
 
 impl FNamePool {
-    pub fn new(base_address: usize) -> Self {
-        Self { base_address, string_offset: AtomicUsize::new(usize::MAX) }
+    pub fn new(base_address: usize, offsets: &UEOffset) -> Self {
+        Self {
+            base_address,
+            string_offset: AtomicUsize::new(usize::MAX),
+            entry_base: offsets.fname_pool_entry_base,
+            stride: offsets.fname_pool_stride,
+            is_chunked: offsets.fname_pool_is_chunked,
+            wide_strings: offsets.wide_strings,
+            atoms_by_id: DashMap::new(),
+            ids_by_name: DashMap::new(),
+        }
+    }
+
+    /// Interns a resolved `(id, name)` pair into both atom-table maps. A no-op for empty names,
+    /// so nothing is ever cached before `string_offset` discovery has actually succeeded (the
+    /// live-read path below only reaches this after a real string came back) and the
+    /// "InvalidName"/zero-length cases `ObjectManager` substitutes stay out of the reverse index.
+    fn intern(&self, id: u32, name: &str) {
+        if name.is_empty() {
+            return;
+        }
+        if self.atoms_by_id.contains_key(&id) {
+            return;
+        }
+        let atom: Arc<str> = Arc::from(name);
+        self.atoms_by_id.insert(id, Arc::clone(&atom));
+        self.ids_by_name.entry(atom).or_default().push(id);
     }
 
     pub fn get_name(&self, process: &Process, id: u32) -> Result<String, String> {
-        let block = id >> 16;
-        let offset = (id & 65535) as usize;
+        if let Some(cached) = self.atoms_by_id.get(&id) {
+            return Ok(cached.to_string());
+        }
 
-        // FNamePool_Entry is at base_address + 0x10
-        let name_pool_entry = self.base_address + 0x10;
+        let (block, offset) = if self.is_chunked { (id >> 16, (id & 65535) as usize) } else { (0, id as usize) };
+
+        let name_pool_entry = self.base_address + self.entry_base;
         let current_block_address = process.memory.read_pointer(name_pool_entry + (block as usize) * 8)?;
 
-        // Offset shift is 2 (2 bytes per char pointer essentially)
-        let name_entry_address = current_block_address + (offset * 2);
+        let name_entry_address = current_block_address + (offset * self.stride);
 
         let name_length = process.memory.read::<u16>(name_entry_address)? >> 6;
 
@@ -62,11 +107,54 @@ impl FNamePool {
         }
 
         let name_str_address = name_entry_address + offset_val;
-        process.memory.read_string(name_str_address, name_length as usize)
+        let name = if self.wide_strings {
+            process.memory.read_wide_string(name_str_address, name_length as usize)?
+        } else {
+            process.memory.read_string(name_str_address, name_length as usize)?
+        };
+        self.intern(id, &name);
+        Ok(name)
+    }
+
+    /// Finds every interned id whose resolved name contains `substring`, through the reverse
+    /// atom-table index — a pure in-memory lookup over ids `get_name` has already resolved at
+    /// some point, no live process reads or pool-wide scan involved. Ids that were never looked
+    /// up (e.g. because nothing has referenced them yet) won't show up until something resolves
+    /// them first. Always case-insensitive: UE's FName comparison is case-insensitive regardless
+    /// of whether a given build's pool is `case_preserving` — that flag only governs which
+    /// casing the *resolved* string keeps for display, not how two names compare.
+    pub fn find_ids_by_name(&self, substring: &str) -> Vec<(u32, String)> {
+        let needle = substring.to_lowercase();
+        let mut results = Vec::new();
+        for entry in self.ids_by_name.iter() {
+            if entry.key().to_lowercase().contains(&needle) {
+                results.extend(entry.value().iter().map(|&id| (id, entry.key().to_string())));
+            }
+        }
+        results
+    }
+
+    /// Reverse lookup: finds the FName id whose resolved string equals `target`. The atom
+    /// table's reverse index (`ids_by_name`) only holds ids that have already been resolved, so
+    /// this does a bounded linear scan instead. Only meant for occasional user-triggered writes
+    /// (e.g. `set_instance_property` on a NameProperty) — never call this from a hot loop.
+    pub fn find_id(&self, process: &Process, target: &str) -> Option<u32> {
+        const MAX_SCAN_ID: u32 = 200_000;
+        (0..MAX_SCAN_ID).find(|&id| self.get_name(process, id).map(|s| s == target).unwrap_or(false))
     }
 
-    /// Multithreaded parser that counts chunks, emits progress
-    pub fn parse_pool(&self, process: &Process, app_handle: &tauri::AppHandle) -> Result<(u32, u32), String> {
+    /// Multithreaded parser that counts chunks, emits progress. `start_batch` resumes a
+    /// previously paused/cancelled run partway through instead of always starting at batch 0.
+    ///
+    /// `cancel_flag`/`run_state` are polled once per batch (mirroring
+    /// `Scanner::scan_aligned_cancellable`'s cancellable region loop): a cancelled batch is
+    /// skipped rather than erroring, and a paused batch blocks in place so no work is lost,
+    /// until `run_state` leaves `PARSE_STATE_PAUSED` or cancellation is requested. Returns
+    /// `(valid_blocks, valid_names_found_so_far, next_batch)`, where `next_batch` equals the
+    /// total batch count when the run finished normally, or the approximate batch to pass back
+    /// as `start_batch` to resume otherwise (batches complete out of order under rayon, so this
+    /// is `start_batch + batches actually completed`, not necessarily the exact first gap).
+    pub fn parse_pool(&self, process: &Process, app_handle: &tauri::AppHandle, cancel_flag: &std::sync::atomic::AtomicBool, run_state: &std::sync::atomic::AtomicU8, start_batch: usize) -> Result<(u32, u32, usize), String> {
         // 讀取 NamePool 的 Chunk 數量
         let name_pool_entry = self.base_address + 0x10;
         let mut valid_blocks = 0;
@@ -95,15 +183,36 @@ impl FNamePool {
         let batch_size = 0x200;
         let num_batches = (total_names_capacity + batch_size - 1) / batch_size;
 
+        // Matches `state::PARSE_STATE_PAUSED` — `name_pool` can't import `state` (which itself
+        // imports `name_pool`), so the shared contract is this literal plus the doc comment above.
+        const RUN_STATE_PAUSED: u8 = 2;
+
         let progress = AtomicUsize::new(0);
         let valid_names_count = AtomicUsize::new(0);
         let dynamic_total_names = AtomicUsize::new(10_000); // 初始目標值
+        let batches_completed = AtomicUsize::new(0);
+        let was_cancelled = std::sync::atomic::AtomicBool::new(false);
 
         // Required to satisfy Send trait over rayon boundaries if Process contains raw HANDLE
         // We know HANDLE is sync/send safe in our context.
         let process_ref = process;
 
-        (0..num_batches).into_par_iter().for_each(|batch_idx| {
+        let remaining_batches: Vec<usize> = (start_batch..num_batches).collect();
+        let remaining_total = remaining_batches.len();
+
+        remaining_batches.into_par_iter().for_each(|batch_idx| {
+            // Pause: block in place (no work lost) until resumed or cancelled.
+            while run_state.load(Ordering::Relaxed) == RUN_STATE_PAUSED && !cancel_flag.load(Ordering::Relaxed) {
+                app_handle.emit("parse-paused", ProgressPayload { current_chunk: batches_completed.load(Ordering::Relaxed), total_chunks: remaining_total, current_names: valid_names_count.load(Ordering::Relaxed), total_names: dynamic_total_names.load(Ordering::Relaxed) }).ok();
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+
+            // Cancel: skip the batch's work entirely rather than erroring.
+            if cancel_flag.load(Ordering::Relaxed) {
+                was_cancelled.store(true, Ordering::Relaxed);
+                return;
+            }
+
             let start = batch_idx * batch_size;
             let end = start + batch_size;
             let mut local_valid_names = 0;
@@ -115,6 +224,7 @@ impl FNamePool {
             }
 
             let current_total_names = valid_names_count.fetch_add(local_valid_names, Ordering::Relaxed) + local_valid_names;
+            batches_completed.fetch_add(1, Ordering::Relaxed);
 
             // 動態擴張 total_names 讓進度條有一種不斷推進的感覺
             let mut current_target = dynamic_total_names.load(Ordering::Relaxed);
@@ -126,16 +236,22 @@ impl FNamePool {
 
             let current = progress.fetch_add(1, Ordering::Relaxed) + 1;
 
-            if current % 10 == 0 || current == num_batches {
-                app_handle.emit("fname-pool-progress", ProgressPayload { current_chunk: current, total_chunks: num_batches, current_names: current_total_names, total_names: current_target }).ok();
+            if current % 10 == 0 || current == remaining_total {
+                app_handle.emit("fname-pool-progress", ProgressPayload { current_chunk: start_batch + current, total_chunks: num_batches, current_names: current_total_names, total_names: current_target }).ok();
             }
         });
 
         let final_count = valid_names_count.load(Ordering::Relaxed);
         let final_target = final_count; // 最後一刻把 total 設成實際的 total，讓進度條 100% 滿格
 
+        if was_cancelled.load(Ordering::Relaxed) {
+            let next_batch = start_batch + batches_completed.load(Ordering::Relaxed);
+            app_handle.emit("parse-cancelled", ProgressPayload { current_chunk: next_batch, total_chunks: num_batches, current_names: final_count, total_names: final_target }).ok();
+            return Ok((valid_blocks as u32, final_count as u32, next_batch));
+        }
+
         app_handle.emit("fname-pool-progress", ProgressPayload { current_chunk: num_batches, total_chunks: num_batches, current_names: final_count, total_names: final_target }).ok();
 
-        Ok((valid_blocks as u32, final_count as u32))
+        Ok((valid_blocks as u32, final_count as u32, num_batches))
     }
 }
@@ -284,8 +284,33 @@ impl GUObjectArray {
         }
     }
 
-    /// Main parser: faithful port of C++ ParseGUObjectArray
-    pub fn parse_array(&self, process: &Process, name_pool: &FNamePool, offsets: &UEOffset, element_size: usize, app_handle: &tauri::AppHandle, obj_mgr: &ObjectManager) -> Result<u32, String> {
+    /// Main parser: faithful port of C++ ParseGUObjectArray.
+    ///
+    /// `start_batch` resumes from a given region index (`i / loop_step`) instead of always
+    /// starting at 0. `cancel_flag`/`run_state` are polled once per region: a paused run blocks
+    /// in place between regions (no work lost) until resumed or cancelled, and a cancelled run
+    /// stops the outer region loop early rather than erroring, returning its partial object
+    /// count plus the region index to resume from. Within a region, the inner rayon batch loop
+    /// also polls `cancel_flag` and skips remaining batches the same way, so cancellation takes
+    /// effect mid-region too instead of only at region boundaries.
+    #[allow(clippy::too_many_arguments)]
+    pub fn parse_array(
+        &self,
+        process: &Process,
+        name_pool: &FNamePool,
+        offsets: &UEOffset,
+        element_size: usize,
+        app_handle: &tauri::AppHandle,
+        obj_mgr: &ObjectManager,
+        cancel_flag: &std::sync::atomic::AtomicBool,
+        run_state: &std::sync::atomic::AtomicU8,
+        start_batch: usize,
+    ) -> Result<(u32, usize), String> {
+        // Matches `state::PARSE_STATE_PAUSED` — `object_array` can't import `state` (which
+        // itself imports `object_array`), so the shared contract is this literal plus this
+        // comment.
+        const RUN_STATE_PAUSED: u8 = 2;
+
         let loop_step: usize = 8; // ProcOffestAdd (64-bit)
 
         // Matching original C++ variable names exactly
@@ -298,13 +323,24 @@ impl GUObjectArray {
         let dynamic_total = AtomicUsize::new(10_000);
 
         // 主程式開始，遞迴 GUObjectArray 找到目標 Object
-        let mut i: usize = 0;
+        let mut i: usize = start_batch.wrapping_mul(loop_step);
+        let mut was_cancelled = false;
         while i < MAX_OBJECT_ARRAY {
             // 終止條件
             if obj_mgr.total_object_count.load(Ordering::Relaxed) > MAX_OBJECT_QUANTITY {
                 break;
             }
 
+            // Pause: block between regions (no work lost) until resumed or cancelled.
+            while run_state.load(Ordering::Relaxed) == RUN_STATE_PAUSED && !cancel_flag.load(Ordering::Relaxed) {
+                app_handle.emit("parse-paused", ProgressPayload { current_chunk: i / loop_step, total_chunks: MAX_OBJECT_ARRAY / loop_step, current_objects: obj_mgr.total_object_count.load(Ordering::Relaxed), total_objects: dynamic_total.load(Ordering::Relaxed) }).ok();
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            if cancel_flag.load(Ordering::Relaxed) {
+                was_cancelled = true;
+                break;
+            }
+
             // ReadMem(Address_Level_1, GUObjectArrayBaseAddress + i)
             let addr_level_1 = match process.memory.read_pointer(self.base_address.wrapping_add(i)) {
                 Ok(addr) => addr,
@@ -349,6 +385,9 @@ impl GUObjectArray {
                 if obj_mgr.total_object_count.load(Ordering::Relaxed) > MAX_OBJECT_QUANTITY {
                     return;
                 }
+                if cancel_flag.load(Ordering::Relaxed) {
+                    return;
+                }
 
                 // size_t Start = i * GUObjectArrayBatchSize  (element index)
                 let start = batch_idx.wrapping_mul(guobject_array_batch_size);
@@ -381,6 +420,13 @@ impl GUObjectArray {
         }
 
         let final_count = obj_mgr.total_object_count.load(Ordering::Relaxed);
+        let next_batch = i / loop_step;
+
+        if was_cancelled {
+            app_handle.emit("parse-cancelled", ProgressPayload { current_chunk: next_batch, total_chunks: MAX_OBJECT_ARRAY / loop_step, current_objects: final_count, total_objects: final_count }).ok();
+            println!("[ GUObjectArray Cancelled ] Resolved {} objects so far, resume from region {}", final_count, next_batch);
+            return Ok((final_count as u32, next_batch));
+        }
 
         // Final progress: 100%
         app_handle.emit("guobject-array-progress", ProgressPayload { current_chunk: 1, total_chunks: 1, current_objects: final_count, total_objects: final_count }).ok();
@@ -388,6 +434,6 @@ impl GUObjectArray {
         println!("[ GUObjectArray Total Objects ] {}", final_count);
         println!("[ GUObjectArray Cache Size ] {}", obj_mgr.cache_by_address.len());
 
-        Ok(final_count as u32)
+        Ok((final_count as u32, next_batch))
     }
 }
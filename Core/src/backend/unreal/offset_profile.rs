@@ -0,0 +1,112 @@
+use crate::backend::unreal::offsets::UEOffset;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// On-disk shape: one `[profile.<name>]` table per game/engine build, each table populating
+/// every field of `UEOffset` (hardcoded member offsets are written as hex, e.g. `member = 0x50`).
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct ProfileFile {
+    #[serde(default, rename = "profile")]
+    profiles: HashMap<String, UEOffset>,
+}
+
+pub const DEFAULT_PROFILE_NAME: &str = "Default";
+
+/// Coarse engine-version bucket used to auto-select an offset profile at attach time. UE's
+/// memory layout (SuperStruct/ClassPrivate/ChildProperty offsets, FName encoding) shifts across
+/// these ranges even though the public reflection API looks identical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineVersion {
+    UE4_2x,
+    UE5_0,
+    UE5_1Plus,
+}
+
+impl EngineVersion {
+    /// Buckets a `major.minor.build.revision` string (as returned by `Process::get_ue_version`)
+    /// into a coarse range. Returns `None` for anything outside the UE4.2x/UE5.x range this
+    /// tool targets.
+    pub fn detect(version: &str) -> Option<Self> {
+        let mut parts = version.split('.');
+        let major: u32 = parts.next()?.parse().ok()?;
+        let minor: u32 = parts.next()?.parse().ok()?;
+
+        match (major, minor) {
+            (4, 20..=29) => Some(EngineVersion::UE4_2x),
+            (5, 0) => Some(EngineVersion::UE5_0),
+            (5, _) => Some(EngineVersion::UE5_1Plus),
+            _ => None,
+        }
+    }
+
+    /// The offset-profile name this bucket resolves to. Nothing is auto-created under this
+    /// name — it only takes effect once a user has saved a profile with this exact name via
+    /// `save_offset_profile`.
+    pub fn profile_name(&self) -> &'static str {
+        match self {
+            EngineVersion::UE4_2x => "UE4.2x",
+            EngineVersion::UE5_0 => "UE5.0",
+            EngineVersion::UE5_1Plus => "UE5.1+",
+        }
+    }
+}
+
+/// Holds every offset profile loaded from disk (or the built-in `UEOffset::default()` fallback
+/// when no file exists yet), so the tool isn't pinned to a single hardcoded UE layout.
+pub struct OffsetProfileStore {
+    profiles: HashMap<String, UEOffset>,
+}
+
+impl OffsetProfileStore {
+    /// Reads `path`; if it's missing or fails to parse, falls back to a single `Default`
+    /// profile built from `UEOffset::default()` so the app still starts.
+    pub fn load_or_default(path: &Path) -> Self {
+        if let Ok(text) = std::fs::read_to_string(path) {
+            match toml::from_str::<ProfileFile>(&text) {
+                Ok(parsed) if !parsed.profiles.is_empty() => {
+                    println!("[offset_profile] Loaded {} profile(s) from {:?}", parsed.profiles.len(), path);
+                    return Self { profiles: parsed.profiles };
+                }
+                Ok(_) => println!("[offset_profile] {:?} contained no profiles, using built-in default", path),
+                Err(e) => println!("[offset_profile] Failed to parse {:?}: {}", path, e),
+            }
+        }
+
+        let mut profiles = HashMap::new();
+        profiles.insert(DEFAULT_PROFILE_NAME.to_string(), UEOffset::default());
+        Self { profiles }
+    }
+
+    pub fn list(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.profiles.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn get(&self, name: &str) -> Option<UEOffset> {
+        self.profiles.get(name).copied()
+    }
+
+    /// Resolves `version` (from `Process::get_ue_version`) to a profile name, but only if a
+    /// profile under that exact name has actually been saved — a detected version with no
+    /// matching profile leaves the caller's existing active profile untouched.
+    pub fn resolve_for_version(&self, version: &str) -> Option<String> {
+        let engine_version = EngineVersion::detect(version)?;
+        let name = engine_version.profile_name();
+        self.profiles.contains_key(name).then(|| name.to_string())
+    }
+
+    /// Adds/overwrites a profile in-memory and persists the full set back to `path`.
+    pub fn save(&mut self, path: &Path, name: String, offsets: UEOffset) -> Result<(), String> {
+        self.profiles.insert(name, offsets);
+        let file = ProfileFile { profiles: self.profiles.clone() };
+        let text = toml::to_string_pretty(&file).map_err(|e| e.to_string())?;
+        std::fs::write(path, text).map_err(|e| e.to_string())
+    }
+}
+
+/// Default on-disk location for the profile file, relative to the working directory the app
+/// was launched from.
+pub fn default_profile_path() -> PathBuf {
+    PathBuf::from("offset_profiles.toml")
+}
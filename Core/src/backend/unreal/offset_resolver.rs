@@ -0,0 +1,306 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::offsets::UEOffset;
+
+/// Which of `UEOffset`'s pointer-chasing fields `OffsetResolver::resolve` managed to validate
+/// against live memory, versus leaving at `UEOffset::default()`'s hardcoded guess because no
+/// candidate offset held up across every sample.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+pub struct OffsetConfidence {
+    pub id: bool,
+    pub fname_index: bool,
+    pub class: bool,
+    pub outer: bool,
+    pub super_struct: bool,
+    pub member: bool,
+    pub prop_size: bool,
+    pub offset: bool,
+}
+
+impl OffsetConfidence {
+    fn log(&self, offsets: &UEOffset) {
+        let field = |name: &str, detected: bool, value: usize| {
+            println!("  -> {:<13} = 0x{:X} [{}]", name, value, if detected { "auto-detected" } else { "default" });
+        };
+        field("id", self.id, offsets.id);
+        field("fname_index", self.fname_index, offsets.fname_index);
+        field("class", self.class, offsets.class);
+        field("outer", self.outer, offsets.outer);
+        field("super_struct", self.super_struct, offsets.super_struct);
+        field("member", self.member, offsets.member);
+        field("prop_size", self.prop_size, offsets.prop_size);
+        field("offset", self.offset, offsets.offset);
+    }
+}
+
+/// One live UObject this resolver sampled off the GUObjectArray, paired with the array slot it
+/// came from — the same `(obj_addr, expected_index)` shape `BaseAddressDumper::detect_element_size`
+/// already validates a candidate element size against, reused here to validate candidate field
+/// offsets instead.
+struct Sample {
+    address: usize,
+    array_index: usize,
+}
+
+const ID_CANDIDATES: [usize; 4] = [0x8, 0xC, 0x10, 0x14];
+const FNAME_INDEX_CANDIDATES: [usize; 5] = [0x10, 0x18, 0x1C, 0x20, 0x28];
+const CLASS_CANDIDATES: [usize; 4] = [0x8, 0x10, 0x18, 0x20];
+const OUTER_CANDIDATES: [usize; 4] = [0x18, 0x20, 0x28, 0x30];
+const SUPER_STRUCT_CANDIDATES: [usize; 4] = [0x40, 0x48, 0x50, 0x58];
+const MEMBER_CANDIDATES: [usize; 5] = [0x48, 0x50, 0x58, 0x60, 0x68];
+const PROP_SIZE_CANDIDATES: [usize; 5] = [0x30, 0x34, 0x38, 0x3C, 0x40];
+const OFFSET_CANDIDATES: [usize; 4] = [0x38, 0x40, 0x44, 0x48];
+
+/// Extends the probing idea already proven in `BaseAddressDumper::detect_element_size` — try a
+/// small set of candidate byte offsets against several live samples, keep whichever one holds up
+/// for all of them — from "detect one field" (element size) to "detect a handful of key
+/// `UEOffset` fields". Every field this can't validate is left at `UEOffset::default()`'s value,
+/// so a failed probe degrades to today's behavior instead of writing out a guess.
+pub struct OffsetResolver;
+
+impl OffsetResolver {
+    /// Empirically resolves `id`, `fname_index`, `class`, `outer`, `super_struct`, `member`,
+    /// `prop_size`, and `offset` from a live GUObjectArray, starting every other field from
+    /// `UEOffset::default()` untouched. Returns the populated offsets alongside which fields were
+    /// actually validated.
+    pub fn resolve(process: &Process, name_pool: &FNamePool, guobject_array_base: usize, element_size: usize) -> (UEOffset, OffsetConfidence) {
+        let mut offsets = UEOffset::default();
+        let mut confidence = OffsetConfidence::default();
+
+        let samples = Self::collect_samples(process, guobject_array_base, element_size);
+        if samples.is_empty() {
+            println!("[OffsetResolver] No live object samples found; falling back to defaults for every field.");
+            confidence.log(&offsets);
+            return (offsets, confidence);
+        }
+        println!("[OffsetResolver] Probing {} live object sample(s) from GUObjectArray...", samples.len());
+
+        if let Some(id_offset) = Self::resolve_id(process, &samples) {
+            offsets.id = id_offset;
+            confidence.id = true;
+        }
+
+        if let Some(fname_index_offset) = Self::resolve_fname_index(process, name_pool, &samples) {
+            offsets.fname_index = fname_index_offset;
+            confidence.fname_index = true;
+        }
+
+        if let Some(class_offset) = Self::resolve_class(process, name_pool, offsets.fname_index, &samples) {
+            offsets.class = class_offset;
+            confidence.class = true;
+
+            // Every sample's class pointer is itself a live UStruct (the UClass describing that
+            // sample's type), so re-anchor the rest of the UStruct-level probes there instead of
+            // on the raw object.
+            let class_samples: Vec<Sample> = samples
+                .iter()
+                .filter_map(|s| {
+                    let class_ptr = process.memory.try_read_pointer(s.address.wrapping_add(class_offset))?;
+                    (class_ptr > 0x10000).then_some(Sample { address: class_ptr, array_index: 0 })
+                })
+                .collect();
+
+            if !class_samples.is_empty() {
+                if let Some(super_offset) = Self::resolve_terminating_chain(process, &SUPER_STRUCT_CANDIDATES, &class_samples) {
+                    offsets.super_struct = super_offset;
+                    confidence.super_struct = true;
+                }
+
+                if let Some(member_offset) = Self::resolve_member(process, &class_samples) {
+                    offsets.member = member_offset;
+                    confidence.member = true;
+
+                    // `offset`/FProperty's own byte offset lives on the first child pulled
+                    // through `member`, not on the UStruct itself — re-anchor there too.
+                    let member_samples: Vec<Sample> = class_samples
+                        .iter()
+                        .filter_map(|s| {
+                            let member_ptr = process.memory.try_read_pointer(s.address.wrapping_add(member_offset))?;
+                            (member_ptr > 0x10000).then_some(Sample { address: member_ptr, array_index: 0 })
+                        })
+                        .collect();
+
+                    if !member_samples.is_empty() {
+                        if let Some(offset_offset) = Self::resolve_small_int(process, &OFFSET_CANDIDATES, &member_samples) {
+                            offsets.offset = offset_offset;
+                            confidence.offset = true;
+                        }
+                    }
+                }
+
+                if let Some(prop_size_offset) = Self::resolve_small_int(process, &PROP_SIZE_CANDIDATES, &class_samples) {
+                    offsets.prop_size = prop_size_offset;
+                    confidence.prop_size = true;
+                }
+            }
+        }
+
+        if let Some(outer_offset) = Self::resolve_terminating_chain(process, &OUTER_CANDIDATES, &samples) {
+            offsets.outer = outer_offset;
+            confidence.outer = true;
+        }
+
+        confidence.log(&offsets);
+        (offsets, confidence)
+    }
+
+    /// Walks the GUObjectArray's two-level pointer indirection the same way
+    /// `BaseAddressDumper::detect_element_size` probes it, but instead of searching for the
+    /// element size (already known here), collects live `(address, array_index)` samples to
+    /// validate candidate field offsets against.
+    fn collect_samples(process: &Process, guobject_array_base: usize, element_size: usize) -> Vec<Sample> {
+        const MAX_SAMPLES: usize = 16;
+        let mut samples = Vec::new();
+
+        for i_raw in (-0x50i32..=0x200).step_by(4) {
+            let entry_addr = guobject_array_base.wrapping_add(i_raw as usize);
+            let ptr = match process.memory.try_read_pointer(entry_addr) {
+                Some(p) if p > 0x10000 => p,
+                _ => continue,
+            };
+
+            let mut addr_level = ptr;
+            for _ in 0..2 {
+                let max_n = 10 * element_size;
+                for n in (0..=max_n).step_by(element_size) {
+                    if samples.len() >= MAX_SAMPLES {
+                        return samples;
+                    }
+                    match process.memory.try_read_pointer(addr_level.wrapping_add(n)) {
+                        Some(obj_addr) if obj_addr > 0x10000 && process.memory.try_read_pointer(obj_addr).is_some() => {
+                            samples.push(Sample { address: obj_addr, array_index: n / element_size });
+                        }
+                        _ => continue,
+                    }
+                }
+
+                match process.memory.try_read_pointer(addr_level) {
+                    Some(p) if p > 0x10000 => addr_level = p,
+                    _ => break,
+                }
+            }
+
+            if samples.len() >= MAX_SAMPLES {
+                break;
+            }
+        }
+
+        samples
+    }
+
+    /// `id`/`InternalIndex` should read back as (approximately) the object's own array slot —
+    /// the same `abs_diff(expected_index) <= 2` tolerance `detect_element_size` already uses,
+    /// since GUObjectArray can leave a few gaps between an object's storage slot and its index.
+    fn resolve_id(process: &Process, samples: &[Sample]) -> Option<usize> {
+        ID_CANDIDATES.iter().copied().find(|&offset| {
+            samples.iter().all(|s| match process.memory.try_read::<i32>(s.address.wrapping_add(offset)) {
+                Some(v) if v >= 0 => (v as usize).abs_diff(s.array_index) <= 2,
+                _ => false,
+            })
+        })
+    }
+
+    /// `fname_index` should read back as an id `FNamePool::get_name` can actually resolve to a
+    /// short, non-empty string for every sample.
+    fn resolve_fname_index(process: &Process, name_pool: &FNamePool, samples: &[Sample]) -> Option<usize> {
+        FNAME_INDEX_CANDIDATES.iter().copied().find(|&offset| {
+            samples.iter().all(|s| match process.memory.try_read::<i32>(s.address.wrapping_add(offset)) {
+                Some(id) if id >= 0 => name_pool.get_name(process, id as u32).map(|n| !n.is_empty() && n.len() < 200).unwrap_or(false),
+                _ => false,
+            })
+        })
+    }
+
+    /// `class` should point at a UObject (the sample's UClass) whose own `class` field — applying
+    /// the same candidate offset again, since UClass is itself a UObject — points at the one
+    /// UObject every build names `"Class"` (`UClass::StaticClass()`'s own class).
+    fn resolve_class(process: &Process, name_pool: &FNamePool, fname_index_offset: usize, samples: &[Sample]) -> Option<usize> {
+        CLASS_CANDIDATES.iter().copied().find(|&offset| {
+            samples.iter().all(|s| {
+                let class_ptr = match process.memory.try_read_pointer(s.address.wrapping_add(offset)) {
+                    Some(p) if p > 0x10000 => p,
+                    _ => return false,
+                };
+                let meta_class_ptr = match process.memory.try_read_pointer(class_ptr.wrapping_add(offset)) {
+                    Some(p) if p > 0x10000 => p,
+                    _ => return false,
+                };
+                let name_id = match process.memory.try_read::<i32>(meta_class_ptr.wrapping_add(fname_index_offset)) {
+                    Some(id) if id >= 0 => id as u32,
+                    _ => return false,
+                };
+                name_pool.get_name(process, name_id).map(|n| n == "Class").unwrap_or(false)
+            })
+        })
+    }
+
+    /// `member`/`Children` is a pointer to the struct's first own `FField`/`UProperty`, or null
+    /// for a struct with no declared properties — so unlike `outer`/`super_struct` this can't
+    /// require a nonzero hop on every sample. Instead requires every sample to read back either
+    /// null or a dereferenceable pointer, and at least one sample to be non-null (so an offset
+    /// that's trivially always-null everywhere can't win by accident).
+    fn resolve_member(process: &Process, samples: &[Sample]) -> Option<usize> {
+        MEMBER_CANDIDATES.iter().copied().find(|&offset| {
+            let mut saw_nonzero = false;
+            let all_plausible = samples.iter().all(|s| match process.memory.try_read_pointer(s.address.wrapping_add(offset)) {
+                Some(0) => true,
+                Some(p) if p > 0x10000 && process.memory.try_read_pointer(p).is_some() => {
+                    saw_nonzero = true;
+                    true
+                }
+                _ => false,
+            });
+            all_plausible && saw_nonzero
+        })
+    }
+
+    /// Shared shape for `prop_size`/`offset`: a small non-negative `i32` size/offset field.
+    /// Requires every sample to read back a plausible size and at least one sample to be nonzero,
+    /// the same "not trivially always zero" guard `resolve_member` uses.
+    fn resolve_small_int(process: &Process, candidates: &[usize], samples: &[Sample]) -> Option<usize> {
+        const MAX_PLAUSIBLE: i32 = 0x4000;
+
+        candidates.iter().copied().find(|&offset| {
+            let mut saw_nonzero = false;
+            let all_plausible = samples.iter().all(|s| match process.memory.try_read::<i32>(s.address.wrapping_add(offset)) {
+                Some(v) if (0..MAX_PLAUSIBLE).contains(&v) => {
+                    saw_nonzero |= v > 0;
+                    true
+                }
+                _ => false,
+            });
+            all_plausible && saw_nonzero
+        })
+    }
+
+    /// Shared shape for `outer`/`super_struct`: both are pointer chains that must terminate at a
+    /// root object (a null/invalid pointer) within a bounded number of hops, the same
+    /// `max_concat = 10` bound `ObjectManager::resolve_full_name`'s own outer-chain walk uses.
+    /// Requires at least one real hop so an offset that's simply always null everywhere (which
+    /// trivially "terminates" immediately) can't win by accident.
+    fn resolve_terminating_chain(process: &Process, candidates: &[usize], samples: &[Sample]) -> Option<usize> {
+        const MAX_HOPS: usize = 10;
+
+        candidates.iter().copied().find(|&offset| {
+            samples.iter().all(|s| {
+                let mut current = s.address;
+                let mut hops = 0usize;
+
+                loop {
+                    let next = match process.memory.try_read_pointer(current.wrapping_add(offset)) {
+                        Some(p) => p,
+                        None => return false,
+                    };
+                    if next < 0x10000 {
+                        return hops > 0;
+                    }
+                    hops += 1;
+                    if hops > MAX_HOPS {
+                        return false;
+                    }
+                    current = next;
+                }
+            })
+        })
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub struct UEOffset {
     pub id: usize,
     pub class: usize,
@@ -41,6 +41,30 @@ pub struct UEOffset {
     pub funct_class: usize,
     pub next_para: usize,
     pub para_type: usize,
+    /// `UFunction::Script`, a `TArray<uint8>` of Kismet bytecode: `{ uint8* Data (+0x0);
+    /// int32 Num (+0x8); int32 Max (+0xC) }` relative to this offset, same TArray layout
+    /// `decode_fstring`/`decode_array_elements` already assume elsewhere in this crate.
+    pub script: usize,
+
+    // ── FNamePool layout ──
+    // `FNamePool::get_name` used to bake these in as `+0x10`/stride-2 literals; they're pulled
+    // out here so a profile for a build with a different pool layout doesn't silently misread.
+    /// Byte offset from the pool's base address to its first block pointer entry.
+    pub fname_pool_entry_base: usize,
+    /// Bytes per name-entry unit within a block (`offset * stride` to get the entry's address).
+    pub fname_pool_stride: usize,
+    /// Whether FNames are split across 64K-entry blocks addressed by `id >> 16`/`id & 0xFFFF`
+    /// (true for every UE4/UE5 build this tool has targeted so far).
+    pub fname_pool_is_chunked: bool,
+
+    // ── feature flags ──
+    /// Whether resolved FName strings retain their original casing (true for UE's default
+    /// `FNAME_CASE_PRESERVING` scheme) vs. being folded to a canonical case by the engine itself.
+    /// Display metadata only — FName comparison is case-insensitive either way, so nothing in
+    /// this crate's matching logic branches on it.
+    pub fname_case_preserving: bool,
+    /// Whether FNamePool entries store UTF-16LE characters instead of ANSI/UTF-8.
+    pub wide_strings: bool,
 }
 
 impl Default for UEOffset {
@@ -91,6 +115,14 @@ impl Default for UEOffset {
             funct_class: outer,
             next_para: 0x48,
             para_type: 0x70,
+            script: 0xE8, // UE5 default, just past UFunction's FunctionFlags/EventGraphCallOffset block
+
+            fname_pool_entry_base: 0x10,
+            fname_pool_stride: 2,
+            fname_pool_is_chunked: true,
+
+            fname_case_preserving: true,
+            wide_strings: false,
         }
     }
 }
@@ -0,0 +1,212 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::member_value::PropertyKind;
+use crate::backend::unreal::name_pool::FNamePool;
+
+/// An (address, type, value) triple registered by `freeze_property`. Re-applied on every tick
+/// of the background freeze loop so the live value keeps snapping back to `value` even while
+/// the target process's own code keeps overwriting it.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FrozenProperty {
+    pub property_type: String,
+    pub bit_mask: u8,
+    pub value: String,
+}
+
+/// Parses `new_value` according to `property_type`'s `PropertyKind` and writes it back through
+/// `process.memory`, mirroring the decode table `read_member_value` uses for reads. Property
+/// kinds that don't have a well-defined in-place write (ObjectRef, Struct, Bytes) are rejected
+/// with an honest error instead of guessing at a layout.
+pub fn write_property_value(process: &Process, name_pool: &FNamePool, address: usize, property_type: &str, bit_mask: u8, new_value: &str) -> Result<(), String> {
+    let type_lower = property_type.to_lowercase();
+    let kind: PropertyKind = property_type.parse().unwrap();
+
+    match kind {
+        PropertyKind::Boolean => {
+            let flag: bool = new_value.parse().map_err(|_| "Expected 'true' or 'false'".to_string())?;
+            let bit = if bit_mask > 0 { bit_mask } else { 1 };
+            let byte = process.memory.try_read::<u8>(address).unwrap_or(0);
+            let updated = if flag { byte | bit } else { byte & !bit };
+            process.memory.write::<u8>(address, updated)
+        }
+
+        PropertyKind::Integer => {
+            if type_lower.contains("int64property") {
+                let v: i64 = new_value.parse().map_err(|_| "Expected a 64-bit integer".to_string())?;
+                process.memory.write::<i64>(address, v)
+            } else if type_lower.contains("uint64property") {
+                let v: u64 = new_value.parse().map_err(|_| "Expected a 64-bit unsigned integer".to_string())?;
+                process.memory.write::<u64>(address, v)
+            } else if type_lower.contains("uintproperty") || type_lower.contains("uint32property") {
+                let v: u32 = new_value.parse().map_err(|_| "Expected a 32-bit unsigned integer".to_string())?;
+                process.memory.write::<u32>(address, v)
+            } else if type_lower.contains("uint16property") {
+                let v: u16 = new_value.parse().map_err(|_| "Expected a 16-bit unsigned integer".to_string())?;
+                process.memory.write::<u16>(address, v)
+            } else if type_lower.contains("int16property") {
+                let v: i16 = new_value.parse().map_err(|_| "Expected a 16-bit integer".to_string())?;
+                process.memory.write::<i16>(address, v)
+            } else {
+                let v: i32 = new_value.parse().map_err(|_| "Expected a 32-bit integer".to_string())?;
+                process.memory.write::<i32>(address, v)
+            }
+        }
+
+        PropertyKind::Float => {
+            if type_lower.contains("doubleproperty") {
+                let v: f64 = new_value.parse().map_err(|_| "Expected a floating-point number".to_string())?;
+                process.memory.write::<f64>(address, v)
+            } else {
+                let v: f32 = new_value.parse().map_err(|_| "Expected a floating-point number".to_string())?;
+                process.memory.write::<f32>(address, v)
+            }
+        }
+
+        PropertyKind::Byte => {
+            let v: u8 = new_value.parse().map_err(|_| "Expected a byte (0-255)".to_string())?;
+            process.memory.write::<u8>(address, v)
+        }
+
+        PropertyKind::Name => {
+            let id = name_pool.find_id(process, new_value).ok_or_else(|| format!("'{}' was not found in the name pool", new_value))?;
+            process.memory.write::<i32>(address, id as i32)
+        }
+
+        PropertyKind::Str => write_fstring(process, address, new_value),
+
+        PropertyKind::ObjectRef | PropertyKind::Struct | PropertyKind::Bytes => Err(format!("Writing is not supported for property kind '{}'", property_type)),
+    }
+}
+
+/// Re-formats `value` the same way `read_back_value` will report it after a successful write
+/// (e.g. `"007"` -> `"7"`, `"1.0"` -> `"1"`, both valid `Display` normalizations Rust's numeric
+/// types apply on parse), so `write_and_verify`'s write-verify comparison isn't comparing the
+/// user's raw input string against a type's canonical numeric form and flagging a successful
+/// write as a mismatch.
+pub fn normalize_value_for_comparison(property_type: &str, value: &str) -> Result<String, String> {
+    let type_lower = property_type.to_lowercase();
+    let kind: PropertyKind = property_type.parse().unwrap();
+
+    Ok(match kind {
+        PropertyKind::Boolean => value.parse::<bool>().map_err(|_| "Expected 'true' or 'false'".to_string())?.to_string(),
+
+        PropertyKind::Integer => {
+            if type_lower.contains("int64property") {
+                value.parse::<i64>().map_err(|_| "Expected a 64-bit integer".to_string())?.to_string()
+            } else if type_lower.contains("uint64property") {
+                value.parse::<u64>().map_err(|_| "Expected a 64-bit unsigned integer".to_string())?.to_string()
+            } else if type_lower.contains("uintproperty") || type_lower.contains("uint32property") {
+                value.parse::<u32>().map_err(|_| "Expected a 32-bit unsigned integer".to_string())?.to_string()
+            } else if type_lower.contains("uint16property") {
+                value.parse::<u16>().map_err(|_| "Expected a 16-bit unsigned integer".to_string())?.to_string()
+            } else if type_lower.contains("int16property") {
+                value.parse::<i16>().map_err(|_| "Expected a 16-bit integer".to_string())?.to_string()
+            } else {
+                value.parse::<i32>().map_err(|_| "Expected a 32-bit integer".to_string())?.to_string()
+            }
+        }
+
+        PropertyKind::Float => {
+            if type_lower.contains("doubleproperty") {
+                value.parse::<f64>().map_err(|_| "Expected a floating-point number".to_string())?.to_string()
+            } else {
+                value.parse::<f32>().map_err(|_| "Expected a floating-point number".to_string())?.to_string()
+            }
+        }
+
+        PropertyKind::Byte => value.parse::<u8>().map_err(|_| "Expected a byte (0-255)".to_string())?.to_string(),
+
+        PropertyKind::Name | PropertyKind::Str | PropertyKind::ObjectRef | PropertyKind::Struct | PropertyKind::Bytes => value.to_string(),
+    })
+}
+
+/// Re-reads `address` with the exact same per-type-name width `write_property_value` just wrote
+/// with (rather than `read_member_value`'s `prop_size`-driven dispatch), so the two stay
+/// symmetric: whatever width we wrote is the width we verify. Used by `set_instance_property`'s
+/// write-verify retry loop to confirm a write actually landed instead of trusting a single pass.
+pub fn read_back_value(process: &Process, name_pool: &FNamePool, address: usize, property_type: &str, bit_mask: u8) -> String {
+    let type_lower = property_type.to_lowercase();
+    let kind: PropertyKind = property_type.parse().unwrap();
+
+    match kind {
+        PropertyKind::Boolean => {
+            let bit = if bit_mask > 0 { bit_mask } else { 1 };
+            let byte = process.memory.try_read::<u8>(address).unwrap_or(0);
+            ((byte & bit) != 0).to_string()
+        }
+
+        PropertyKind::Integer => {
+            if type_lower.contains("int64property") {
+                process.memory.try_read::<i64>(address).unwrap_or(0).to_string()
+            } else if type_lower.contains("uint64property") {
+                process.memory.try_read::<u64>(address).unwrap_or(0).to_string()
+            } else if type_lower.contains("uintproperty") || type_lower.contains("uint32property") {
+                process.memory.try_read::<u32>(address).unwrap_or(0).to_string()
+            } else if type_lower.contains("uint16property") {
+                process.memory.try_read::<u16>(address).unwrap_or(0).to_string()
+            } else if type_lower.contains("int16property") {
+                process.memory.try_read::<i16>(address).unwrap_or(0).to_string()
+            } else {
+                process.memory.try_read::<i32>(address).unwrap_or(0).to_string()
+            }
+        }
+
+        PropertyKind::Float => {
+            if type_lower.contains("doubleproperty") {
+                process.memory.try_read::<f64>(address).unwrap_or(0.0).to_string()
+            } else {
+                process.memory.try_read::<f32>(address).unwrap_or(0.0).to_string()
+            }
+        }
+
+        PropertyKind::Byte => process.memory.try_read::<u8>(address).unwrap_or(0).to_string(),
+
+        PropertyKind::Name => {
+            let id = process.memory.try_read::<i32>(address).unwrap_or(0);
+            name_pool.get_name(process, id as u32).unwrap_or_default()
+        }
+
+        PropertyKind::Str => read_fstring(process, address).unwrap_or_default(),
+
+        PropertyKind::ObjectRef | PropertyKind::Struct | PropertyKind::Bytes => String::new(),
+    }
+}
+
+/// Decodes an `FString` (TArray<TCHAR>) at `addr` for `read_back_value`'s `PropertyKind::Str`
+/// case. Mirrors `write_fstring`'s layout assumptions (`Data`/`Num` at `+0x0`/`+0x8`).
+fn read_fstring(process: &Process, addr: usize) -> Option<String> {
+    let data_ptr = process.memory.try_read_pointer(addr)?;
+    let num = process.memory.try_read::<i32>(addr.wrapping_add(0x8)).unwrap_or(0);
+    if data_ptr <= 0x10000 || num <= 0 || num > 0x10000 {
+        return None;
+    }
+
+    let bytes = process.memory.read_bytes(data_ptr, num as usize * 2).ok()?;
+    let wide: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+    Some(String::from_utf16_lossy(&wide).trim_end_matches('\0').to_string())
+}
+
+/// Writes `new_value` into an already-allocated FString (TArray<TCHAR>) in place. Only
+/// supports values that fit within the buffer's existing `Max` (at `addr+0xC`) — growing the
+/// allocation would mean invoking the target process's own allocator, which is out of scope for
+/// a memory-editing tool like this one.
+fn write_fstring(process: &Process, addr: usize, new_value: &str) -> Result<(), String> {
+    let data_ptr = process.memory.try_read_pointer(addr).unwrap_or(0);
+    let max = process.memory.try_read::<i32>(addr.wrapping_add(0xC)).unwrap_or(0);
+
+    if data_ptr < 0x10000 || max <= 0 {
+        return Err("FString has no backing buffer to write into".to_string());
+    }
+
+    let wide: Vec<u16> = new_value.encode_utf16().chain(std::iter::once(0)).collect();
+    if wide.len() > max as usize {
+        return Err(format!("New value ({} chars) doesn't fit in the existing buffer (capacity {})", new_value.chars().count(), max - 1));
+    }
+
+    let mut bytes = Vec::with_capacity(wide.len() * 2);
+    for c in &wide {
+        bytes.extend_from_slice(&c.to_le_bytes());
+    }
+
+    process.memory.write_bytes(data_ptr, &bytes)?;
+    process.memory.write::<i32>(addr.wrapping_add(0x8), wide.len() as i32)
+}
@@ -0,0 +1,408 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A single object in an `ObjectGraph`: its address plus the name/type `ObjectManager` already
+/// resolves for it, so a caller doesn't have to re-look the address up in `cache_by_address`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GraphNode {
+    pub address: usize,
+    pub name: String,
+    pub type_name: String,
+}
+
+/// A directed edge: `source` holds a pointer to `target` through the property named
+/// `property_name` (e.g. `"Owner"`, or `"Inventory[3]"` for an object-typed array element).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GraphEdge {
+    pub source: usize,
+    pub target: usize,
+    pub property_name: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ObjectGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// Caps how many objects `build_object_graph` will visit, independent of `max_depth` — UE object
+/// graphs are routinely cyclic (an Actor pointing back at its owning World, a Component back at
+/// its Actor), so depth alone doesn't bound the work. Visited-set cycle protection keeps
+/// traversal from looping forever; this cap keeps one call responsive even on a wide graph.
+const MAX_GRAPH_NODES: usize = 2_000;
+
+/// Caps how many elements of an object-typed `TArray` are followed per property, mirroring
+/// `commands::decode_array_elements`'s own `MAX_ARRAY_ELEMENTS` cap on the display side.
+const MAX_ARRAY_REFS: usize = 50;
+
+/// Breadth-first walk of every `ObjectProperty`/`ClassProperty`/`WeakObjectProperty`/
+/// `InterfaceProperty` reachable from `root`, including such properties nested inside
+/// object-typed `TArray`/`TSet`/`TMap` elements, up to `max_depth` hops or `MAX_GRAPH_NODES`
+/// visited objects, whichever comes first. `visited` doubles as cycle protection: a node already
+/// queued is never re-queued, so the common Actor <-> Component <-> World cycles terminate
+/// instead of looping.
+pub fn build_object_graph(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, root: usize, max_depth: usize) -> ObjectGraph {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+
+    if root <= 0x10000 {
+        return ObjectGraph { nodes, edges };
+    }
+
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    queue.push_back((root, 0));
+    visited.insert(root);
+
+    while let Some((address, hops)) = queue.pop_front() {
+        if nodes.len() >= MAX_GRAPH_NODES {
+            break;
+        }
+
+        let Some(obj) = obj_mgr.try_save_object(address, proc, name_pool, offsets, 0, 5) else { continue };
+        nodes.push(GraphNode { address, name: obj.name.clone(), type_name: obj.type_name.clone() });
+
+        if hops >= max_depth || obj.class_ptr <= 0x10000 {
+            continue;
+        }
+
+        for (property_name, target) in object_references(proc, obj_mgr, name_pool, offsets, address, obj.class_ptr) {
+            edges.push(GraphEdge { source: address, target, property_name });
+            if nodes.len() + queue.len() < MAX_GRAPH_NODES && visited.insert(target) {
+                queue.push_back((target, hops + 1));
+            }
+        }
+    }
+
+    ObjectGraph { nodes, edges }
+}
+
+/// BFS over `graph`'s adjacency from `from` to `to`, returning the chain of property names
+/// connecting them (e.g. `["Owner", "Inventory[3]"]`) if a path exists. Operates purely on an
+/// already-built `ObjectGraph` rather than walking live memory itself — call `build_object_graph`
+/// first with a `root`/`max_depth` wide enough to contain both endpoints.
+pub fn find_paths(graph: &ObjectGraph, from: usize, to: usize) -> Option<Vec<String>> {
+    if from == to {
+        return Some(Vec::new());
+    }
+
+    let mut adjacency: HashMap<usize, Vec<&GraphEdge>> = HashMap::new();
+    for edge in &graph.edges {
+        adjacency.entry(edge.source).or_default().push(edge);
+    }
+
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut queue: VecDeque<(usize, Vec<String>)> = VecDeque::new();
+    queue.push_back((from, Vec::new()));
+    visited.insert(from);
+
+    while let Some((address, path)) = queue.pop_front() {
+        let Some(out_edges) = adjacency.get(&address) else { continue };
+        for edge in out_edges {
+            let mut next_path = path.clone();
+            next_path.push(edge.property_name.clone());
+
+            if edge.target == to {
+                return Some(next_path);
+            }
+            if visited.insert(edge.target) {
+                queue.push_back((edge.target, next_path));
+            }
+        }
+    }
+
+    None
+}
+
+/// Reverse lookup: "who points at `target`?" — sweeps every object `ObjectManager` already knows
+/// about (`cache_by_address`) and re-runs the same `object_references` walk `build_object_graph`
+/// uses forward, keeping only the edges that resolve to `target`. Unlike `find_paths`, this isn't
+/// limited to one already-built graph: it can surface a referencer `build_object_graph` never
+/// reached from its root.
+pub fn find_referencers(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, target: usize) -> Vec<GraphEdge> {
+    let mut referencers = Vec::new();
+
+    for entry in obj_mgr.cache_by_address.iter() {
+        let obj = entry.value();
+        if obj.class_ptr <= 0x10000 {
+            continue;
+        }
+
+        for (property_name, ptr) in object_references(proc, obj_mgr, name_pool, offsets, obj.address, obj.class_ptr) {
+            if ptr == target {
+                referencers.push(GraphEdge { source: obj.address, target, property_name });
+            }
+        }
+    }
+
+    referencers
+}
+
+/// Walks `class_addr`'s member linked list rooted at `inst_addr` (the same ChildProperty chain
+/// `commands::walk_instance_properties` walks for display), returning every resolved object
+/// pointer behind an object-typed property, paired with the property name that held it. Unlike
+/// `walk_instance_properties` this only extracts the pointer, not a rendered display value — a
+/// different consumer over the same layout.
+fn object_references(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, inst_addr: usize, class_addr: usize) -> Vec<(String, usize)> {
+    let mut refs = Vec::new();
+
+    let mut child_addr = proc.memory.try_read_pointer(class_addr.wrapping_add(offsets.member)).unwrap_or(0);
+    let mut safety = 0;
+
+    while child_addr > 0x10000 && safety < 500 {
+        safety += 1;
+
+        let child_name_id = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+        let child_name = name_pool.get_name(proc, child_name_id as u32).unwrap_or_default();
+
+        let child_type_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+        let child_type_id = proc.memory.try_read::<i32>(child_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+        let child_type = name_pool.get_name(proc, child_type_id as u32).unwrap_or_default();
+        let type_lower = child_type.to_lowercase();
+
+        if !child_name.is_empty() {
+            let offset_val = proc.memory.try_read::<i32>(child_addr.wrapping_add(offsets.offset)).unwrap_or(0) as usize;
+
+            if is_object_property(&type_lower) {
+                let ptr = proc.memory.try_read_pointer(inst_addr.wrapping_add(offset_val)).unwrap_or(0);
+                if ptr > 0x10000 {
+                    obj_mgr.try_save_object(ptr, proc, name_pool, offsets, 0, 5);
+                    refs.push((child_name.clone(), ptr));
+                }
+            } else if type_lower.contains("arrayproperty") {
+                let array_addr = inst_addr.wrapping_add(offset_val);
+                refs.extend(object_array_references(proc, obj_mgr, name_pool, offsets, child_addr, array_addr, &child_name));
+            } else if type_lower.contains("setproperty") {
+                let set_addr = inst_addr.wrapping_add(offset_val);
+                refs.extend(set_references(proc, obj_mgr, name_pool, offsets, child_addr, set_addr, &child_name));
+            } else if type_lower.contains("mapproperty") {
+                let map_addr = inst_addr.wrapping_add(offset_val);
+                refs.extend(map_references(proc, obj_mgr, name_pool, offsets, child_addr, map_addr, &child_name));
+            }
+        }
+
+        child_addr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+    }
+
+    refs
+}
+
+fn is_object_property(type_lower: &str) -> bool {
+    type_lower.contains("objectproperty") || type_lower.contains("classproperty") || type_lower.contains("weakobjectproperty") || type_lower.contains("interfaceproperty")
+}
+
+/// Same `TArray` layout `commands::decode_array_elements` reads (`Data`/`Num` at `+0x0`/`+0x8`,
+/// element stride from the owning `FArrayProperty`'s `Inner`), but only follows elements whose
+/// `Inner` is itself object-typed — everything else has no pointer worth graphing.
+fn object_array_references(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, child_addr: usize, array_addr: usize, property_name: &str) -> Vec<(String, usize)> {
+    let mut refs = Vec::new();
+
+    let data_ptr = proc.memory.try_read_pointer(array_addr).unwrap_or(0);
+    let num = proc.memory.try_read::<i32>(array_addr.wrapping_add(0x8)).unwrap_or(0);
+    if data_ptr <= 0x10000 || num <= 0 {
+        return refs;
+    }
+
+    let inner_ptr = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.array)).unwrap_or(0);
+    if inner_ptr <= 0x10000 {
+        return refs;
+    }
+
+    let inner_type_ptr = proc.memory.try_read_pointer(inner_ptr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+    let inner_type_id = proc.memory.try_read::<i32>(inner_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+    let inner_type = name_pool.get_name(proc, inner_type_id as u32).unwrap_or_default();
+    if !is_object_property(&inner_type.to_lowercase()) {
+        return refs;
+    }
+
+    let element_size = proc.memory.try_read::<i32>(inner_ptr.wrapping_add(offsets.prop_size)).unwrap_or(0);
+    if element_size <= 0 {
+        return refs;
+    }
+
+    let element_count = (num as usize).min(MAX_ARRAY_REFS);
+    for i in 0..element_count {
+        let element_addr = data_ptr.wrapping_add(i * element_size as usize);
+        let ptr = proc.memory.try_read_pointer(element_addr).unwrap_or(0);
+        if ptr > 0x10000 {
+            obj_mgr.try_save_object(ptr, proc, name_pool, offsets, 0, 5);
+            refs.push((format!("{}[{}]", property_name, i), ptr));
+        }
+    }
+
+    refs
+}
+
+/// Trailing bytes UE appends after each live `FScriptSet`/`FScriptMap` slot's payload (the
+/// `HashNextId` used to walk that hash bucket's collision chain) — needed to get the slot stride
+/// right, same role `element_size` plays for `object_array_references`'s `TArray` stride.
+const SET_ELEMENT_HASH_LINK_SIZE: usize = 4;
+
+/// The natural (C-ABI) alignment of a scalar/pointer type of this size — correct for every
+/// property type this file actually cares about (pointers and the fixed-width integers/floats
+/// `PropertyKind::Integer`/`Float` covers), since none of them are over-aligned structs.
+fn natural_alignment(size: usize) -> usize {
+    match size {
+        0 | 1 => 1,
+        2 | 3 => 2,
+        4..=7 => 4,
+        _ => 8,
+    }
+}
+
+/// Rounds `value` up to the next multiple of `align` (`align` must be a power of two).
+fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+/// Caps how many bits of a `TBitArray` this file will read off `AllocationFlags`, so a garbage
+/// `FScriptSparseArray` (e.g. an unresolved offset profile pointed at the wrong member) can't
+/// make `read_sparse_array` allocate or loop on an absurd bit count.
+const MAX_SPARSE_ARRAY_BITS: usize = 100_000;
+
+/// The decoded shape of an `FScriptSparseArray` — the common storage both `FScriptSet` and
+/// `FScriptMap` build on: a packed element buffer plus a `TBitArray` marking which slots are
+/// live (freed slots are reused and left as garbage rather than zeroed). Reverse-engineered the
+/// same way every other struct layout in this file is: `Data` at `+0x00`, the bit array's own
+/// `Data`/`NumBits` at `+0x08`/`+0x10`, both widely stable across UE4/UE5 builds.
+struct SparseArrayLayout {
+    data: usize,
+    live_indices: Vec<usize>,
+}
+
+fn read_sparse_array(proc: &Process, sparse_addr: usize) -> Option<SparseArrayLayout> {
+    let data = proc.memory.try_read_pointer(sparse_addr)?;
+    let flags_data = proc.memory.try_read_pointer(sparse_addr.wrapping_add(0x08))?;
+    let num_bits = proc.memory.try_read::<i32>(sparse_addr.wrapping_add(0x10)).unwrap_or(0);
+
+    if data <= 0x10000 || flags_data <= 0x10000 || num_bits <= 0 || num_bits as usize > MAX_SPARSE_ARRAY_BITS {
+        return None;
+    }
+
+    let word_count = (num_bits as usize + 31) / 32;
+    let words = proc.memory.read_bytes(flags_data, word_count * 4).ok()?;
+
+    let mut live_indices = Vec::new();
+    for index in 0..num_bits as usize {
+        let word = u32::from_le_bytes(words[index / 32 * 4..index / 32 * 4 + 4].try_into().unwrap());
+        if (word >> (index % 32)) & 1 != 0 {
+            live_indices.push(index);
+        }
+    }
+
+    Some(SparseArrayLayout { data, live_indices })
+}
+
+/// Same object-typed-element filtering `object_array_references` does for `TArray`, but over a
+/// `TSet`'s `FScriptSet` (an `FScriptSparseArray` of bare elements, no key/value split).
+fn set_references(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, child_addr: usize, set_addr: usize, property_name: &str) -> Vec<(String, usize)> {
+    let mut refs = Vec::new();
+
+    let element_prop = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.array)).unwrap_or(0);
+    if element_prop <= 0x10000 {
+        return refs;
+    }
+
+    let element_type_ptr = proc.memory.try_read_pointer(element_prop.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+    let element_type_id = proc.memory.try_read::<i32>(element_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+    let element_type = name_pool.get_name(proc, element_type_id as u32).unwrap_or_default();
+    if !is_object_property(&element_type.to_lowercase()) {
+        return refs;
+    }
+
+    let element_size = proc.memory.try_read::<i32>(element_prop.wrapping_add(offsets.prop_size)).unwrap_or(0);
+    if element_size <= 0 {
+        return refs;
+    }
+
+    let Some(sparse) = read_sparse_array(proc, set_addr) else { return refs };
+
+    // `TSetElement<T>` is `{ T Value; FSetElementId HashNextId; }` — align the struct to
+    // `max(alignof(T), alignof(int32))` the same way the C++ compiler would, instead of just
+    // adding the two sizes, or every slot past index 0 is read from the wrong offset.
+    let element_align = natural_alignment(element_size as usize).max(4);
+    let stride = align_up(element_size as usize + SET_ELEMENT_HASH_LINK_SIZE, element_align);
+
+    for (i, &index) in sparse.live_indices.iter().take(MAX_ARRAY_REFS).enumerate() {
+        let element_addr = sparse.data.wrapping_add(index * stride);
+        let ptr = proc.memory.try_read_pointer(element_addr).unwrap_or(0);
+        if ptr > 0x10000 {
+            obj_mgr.try_save_object(ptr, proc, name_pool, offsets, 0, 5);
+            refs.push((format!("{}[{}]", property_name, i), ptr));
+        }
+    }
+
+    refs
+}
+
+/// Follows a `TMap`'s `FScriptMap` (an `FScriptSparseArray` of `{Key, Value}` pairs, `KeyProp`/
+/// `ValueProp` read off `child_addr` at `offsets.map_key`/`offsets.map_value`), emitting an edge
+/// for whichever side(s) of the pair are object-typed — `"Prop[i].Key"`/`"Prop[i].Value"`, since
+/// unlike an array element a map entry can have an object on both sides at once.
+fn map_references(proc: &Process, obj_mgr: &ObjectManager, name_pool: &FNamePool, offsets: &UEOffset, child_addr: usize, map_addr: usize, property_name: &str) -> Vec<(String, usize)> {
+    let mut refs = Vec::new();
+
+    let key_prop = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.map_key)).unwrap_or(0);
+    let value_prop = proc.memory.try_read_pointer(child_addr.wrapping_add(offsets.map_value)).unwrap_or(0);
+    if key_prop <= 0x10000 || value_prop <= 0x10000 {
+        return refs;
+    }
+
+    let key_size = proc.memory.try_read::<i32>(key_prop.wrapping_add(offsets.prop_size)).unwrap_or(0);
+    let value_size = proc.memory.try_read::<i32>(value_prop.wrapping_add(offsets.prop_size)).unwrap_or(0);
+    if key_size <= 0 || value_size <= 0 {
+        return refs;
+    }
+
+    let key_type_ptr = proc.memory.try_read_pointer(key_prop.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+    let key_type_id = proc.memory.try_read::<i32>(key_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+    let key_is_object = is_object_property(&name_pool.get_name(proc, key_type_id as u32).unwrap_or_default().to_lowercase());
+
+    let value_type_ptr = proc.memory.try_read_pointer(value_prop.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+    let value_type_id = proc.memory.try_read::<i32>(value_type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+    let value_is_object = is_object_property(&name_pool.get_name(proc, value_type_id as u32).unwrap_or_default().to_lowercase());
+
+    if !key_is_object && !value_is_object {
+        return refs;
+    }
+
+    let Some(sparse) = read_sparse_array(proc, map_addr) else { return refs };
+
+    // `TPair<Key, Value>` pads `Value` up to its own alignment, and the wrapping
+    // `TSetElement<TPair<...>>` (`FScriptMap` is really an `FScriptSet` of pairs) rounds the
+    // whole slot up to `max(alignof(Key), alignof(Value), alignof(int32))` for the trailing
+    // `HashNextId` — flat `key_size + value_size` addition only happens to hold when that sum is
+    // already a multiple of the pair's alignment (e.g. `TMap<int32, UObject*>` isn't: 4 + 8 = 12
+    // needs 4 bytes of padding before the 8-byte-aligned pointer).
+    let key_align = natural_alignment(key_size as usize);
+    let value_align = natural_alignment(value_size as usize);
+    let pair_align = key_align.max(value_align).max(4);
+    let value_offset = align_up(key_size as usize, value_align);
+    let pair_size = align_up(value_offset + value_size as usize, pair_align);
+    let stride = align_up(pair_size + SET_ELEMENT_HASH_LINK_SIZE, pair_align);
+
+    for (i, &index) in sparse.live_indices.iter().take(MAX_ARRAY_REFS).enumerate() {
+        let pair_addr = sparse.data.wrapping_add(index * stride);
+
+        if key_is_object {
+            let ptr = proc.memory.try_read_pointer(pair_addr).unwrap_or(0);
+            if ptr > 0x10000 {
+                obj_mgr.try_save_object(ptr, proc, name_pool, offsets, 0, 5);
+                refs.push((format!("{}[{}].Key", property_name, i), ptr));
+            }
+        }
+
+        if value_is_object {
+            let ptr = proc.memory.try_read_pointer(pair_addr.wrapping_add(value_offset)).unwrap_or(0);
+            if ptr > 0x10000 {
+                obj_mgr.try_save_object(ptr, proc, name_pool, offsets, 0, 5);
+                refs.push((format!("{}[{}].Value", property_name, i), ptr));
+            }
+        }
+    }
+
+    refs
+}
@@ -0,0 +1,106 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+
+/// One instruction in a declarative pointer-chase program. A `Vec<ResolveStep>` is run by
+/// [`resolve`] against a starting address (e.g. a `UProperty`'s `this`) to recover the name of
+/// whatever it references — the sub-type of a `StructProperty`/`ObjectProperty`/etc, or an
+/// `Enum`'s underlying type. Different UE builds order `Property_0`/`Property_8` differently or
+/// stash the type `FName` at a non-standard offset; swapping the step list (e.g. from a loaded
+/// offset profile) handles that without touching this crate's code.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum ResolveStep {
+    /// Dereference the pointer at `current_address + offset`, replacing the current address.
+    /// Fails the step (and any enclosing `FirstOf` branch) if the pointer is null/invalid.
+    ReadPtr(usize),
+    /// Read the `FName` id stored as an `i32` at `current_address + offset` and resolve it via
+    /// the shared `FNamePool`, finishing the program with that name. Use this instead of
+    /// `ResolveName` when a build stores the type `FName` at a non-default offset.
+    ReadI32(usize),
+    /// Like `ReadI32`, but reads the id from `UEOffset::fname_index` — the common case.
+    ResolveName,
+    /// Look the current address up in `ObjectManager::cache_by_address`; finishes the program
+    /// with the cached object's own name if present.
+    TryCache,
+    /// Try each step in order against the current address, taking the first one that doesn't
+    /// fail. Used to express fallback chains such as "try `Property_8`, else `Property_0`, else
+    /// `TypeObject`".
+    FirstOf(Vec<ResolveStep>),
+}
+
+/// Read-only handles a [`ResolveStep`] program needs — threaded through instead of captured, so
+/// the same step list can be reused across objects/processes.
+pub struct ResolveContext<'a> {
+    pub process: &'a Process,
+    pub obj_mgr: &'a ObjectManager,
+    pub name_pool: &'a FNamePool,
+    pub offsets: &'a UEOffset,
+}
+
+enum StepOutcome {
+    /// Chain continues: the program's "current address" becomes this.
+    Address(usize),
+    /// Program is done: a name (and the address it was resolved from) was found.
+    Resolved(String, usize),
+}
+
+fn read_name_at(ctx: &ResolveContext, addr: usize, name_offset: usize) -> Option<StepOutcome> {
+    let name_id = ctx.process.memory.try_read::<i32>(addr.wrapping_add(name_offset))?;
+    let name = ctx.name_pool.get_name(ctx.process, name_id as u32).ok()?;
+    if name.is_empty() {
+        None
+    } else {
+        Some(StepOutcome::Resolved(name, addr))
+    }
+}
+
+fn run_step(step: &ResolveStep, addr: usize, ctx: &ResolveContext) -> Option<StepOutcome> {
+    match step {
+        ResolveStep::ReadPtr(offset) => {
+            let next = ctx.process.memory.try_read_pointer(addr.wrapping_add(*offset))?;
+            if next > 0x10000 {
+                Some(StepOutcome::Address(next))
+            } else {
+                None
+            }
+        }
+        ResolveStep::ReadI32(offset) => read_name_at(ctx, addr, *offset),
+        ResolveStep::ResolveName => read_name_at(ctx, addr, ctx.offsets.fname_index),
+        ResolveStep::TryCache => {
+            let obj = ctx.obj_mgr.cache_by_address.get(&addr)?;
+            Some(StepOutcome::Resolved(obj.name.clone(), obj.address))
+        }
+        ResolveStep::FirstOf(alternatives) => alternatives.iter().find_map(|s| run_step(s, addr, ctx)),
+    }
+}
+
+/// Runs `steps` in order starting from `start_address`, threading the resolved address from one
+/// step to the next. Stops at the first step that resolves a name; returns `None` if any step
+/// along the way fails (no alternative in a `FirstOf` panned out either).
+pub fn resolve(steps: &[ResolveStep], start_address: usize, ctx: &ResolveContext) -> Option<(String, usize)> {
+    let mut current = start_address;
+    for step in steps {
+        match run_step(step, current, ctx)? {
+            StepOutcome::Address(addr) => current = addr,
+            StepOutcome::Resolved(name, addr) => return Some((name, addr)),
+        }
+    }
+    None
+}
+
+/// The default sub-type step list: take the first of `Property_8`, `Property_0`, `TypeObject`
+/// that holds a non-null pointer as the sub-object's address, then prefer the cached object's
+/// own name before falling back to reading its `FName` directly. Only that first non-null
+/// pointer is resolved: if neither the cache nor its `FName` yields a name, the later
+/// candidates are not tried and `resolve` returns `None`.
+pub fn default_sub_type_steps(offsets: &UEOffset) -> Vec<ResolveStep> {
+    vec![
+        ResolveStep::FirstOf(vec![
+            ResolveStep::ReadPtr(offsets.property + 8),
+            ResolveStep::ReadPtr(offsets.property),
+            ResolveStep::ReadPtr(offsets.type_object),
+        ]),
+        ResolveStep::FirstOf(vec![ResolveStep::TryCache, ResolveStep::ResolveName]),
+    ]
+}
@@ -0,0 +1,764 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// One generated C++ header: either a per-package file or the master include.
+pub struct GeneratedHeader {
+    pub package_name: String,
+    pub file_name: String,
+    pub content: String,
+}
+
+/// Output format for `export_sdk_to_disk`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SdkExportFormat {
+    Cpp,
+    Rust,
+    Json,
+}
+
+impl std::str::FromStr for SdkExportFormat {
+    type Err = std::convert::Infallible;
+
+    /// Defaults to `Cpp` for anything that isn't recognized as `"json"`/`"rust"`, mirroring
+    /// `PropertyKind::from_str`'s never-fails fallback pattern.
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        Ok(if format.eq_ignore_ascii_case("json") {
+            SdkExportFormat::Json
+        } else if format.eq_ignore_ascii_case("rust") {
+            SdkExportFormat::Rust
+        } else {
+            SdkExportFormat::Cpp
+        })
+    }
+}
+
+#[derive(Clone)]
+struct SdkProperty {
+    name: String,
+    ue_type: String,
+    cpp_type: String,
+    sub_type: String,
+    offset: usize,
+    size: usize,
+    bit_mask: u8,
+    dependency: Option<usize>, // address of a StructProperty sub-type, used for ordering
+}
+
+#[derive(Clone)]
+struct SdkFunction {
+    name: String,
+    return_type: String,
+    params: Vec<(String, String)>, // (cpp_type, name)
+}
+
+enum SdkKind {
+    Class,
+    Struct,
+    Enum,
+}
+
+struct SdkType {
+    address: usize,
+    name: String,
+    package: String,
+    super_address: usize,
+    kind: SdkKind,
+    size: usize,
+    properties: Vec<SdkProperty>,
+    enum_values: Vec<(String, i64)>,
+    enum_underlying: String,
+    functions: Vec<SdkFunction>,
+}
+
+/// Mirrors `commands::extract_package_name` (kept private there, so duplicated here rather
+/// than reaching across layers — `unreal/` doesn't depend on `commands/`).
+fn extract_package_name(input: &str) -> String {
+    let first_slash = match input.find('/') {
+        Some(idx) => idx,
+        None => return String::new(),
+    };
+    let second_slash = match input[first_slash + 1..].find('/') {
+        Some(idx) => first_slash + 1 + idx,
+        None => return String::new(),
+    };
+    if let Some(idx) = input[second_slash + 1..].find('/') {
+        let third_slash = second_slash + 1 + idx;
+        return input[first_slash..third_slash].to_string();
+    }
+    if let Some(idx) = input[second_slash + 1..].find('.') {
+        let dot_pos = second_slash + 1 + idx;
+        return input[first_slash..dot_pos].to_string();
+    }
+    input[first_slash..].to_string()
+}
+
+fn sanitize(package: &str) -> String {
+    let trimmed = package.trim_start_matches('/');
+    trimmed.replace('/', "_")
+}
+
+/// Maps a property's UE type name (plus its resolved sub-type, if any) onto a C++ type name
+/// usable in a reconstructed header.
+fn to_cpp_type(property_type: &str, sub_type: &str, sub_type_kind_is_class: bool) -> String {
+    let t = property_type.to_lowercase();
+    if t.contains("boolproperty") {
+        "bool".to_string()
+    } else if t.contains("byteproperty") {
+        "uint8".to_string()
+    } else if t.contains("int64property") {
+        "int64".to_string()
+    } else if t.contains("uint64property") {
+        "uint64".to_string()
+    } else if t.contains("int16property") {
+        "int16".to_string()
+    } else if t.contains("uint16property") {
+        "uint16".to_string()
+    } else if t.contains("uint32property") {
+        "uint32".to_string()
+    } else if t.contains("intproperty") || t.contains("int32property") {
+        "int32".to_string()
+    } else if t.contains("doubleproperty") {
+        "double".to_string()
+    } else if t.contains("floatproperty") {
+        "float".to_string()
+    } else if t.contains("nameproperty") {
+        "FName".to_string()
+    } else if t.contains("strproperty") {
+        "FString".to_string()
+    } else if t.contains("textproperty") {
+        "FText".to_string()
+    } else if t.contains("enumproperty") {
+        if sub_type.is_empty() {
+            "uint8".to_string()
+        } else {
+            sub_type.to_string()
+        }
+    } else if t.contains("arrayproperty") {
+        if sub_type.is_empty() {
+            "TArray<void*>".to_string()
+        } else {
+            format!("TArray<{}>", sub_type)
+        }
+    } else if t.contains("setproperty") {
+        if sub_type.is_empty() {
+            "TSet<void*>".to_string()
+        } else {
+            format!("TSet<{}>", sub_type)
+        }
+    } else if t.contains("mapproperty") {
+        if sub_type.is_empty() {
+            "TMap<void*, void*>".to_string()
+        } else {
+            format!("TMap<{}>", sub_type)
+        }
+    } else if t.contains("structproperty") {
+        if sub_type.is_empty() {
+            "void*".to_string()
+        } else {
+            sub_type.to_string()
+        }
+    } else if t.contains("objectproperty") || t.contains("classproperty") || t.contains("softobjectproperty") || t.contains("weakobjectproperty") || t.contains("softclassproperty") || t.contains("interfaceproperty") {
+        if sub_type.is_empty() {
+            "class UObject*".to_string()
+        } else if sub_type_kind_is_class {
+            format!("class {}*", sub_type)
+        } else {
+            format!("{}*", sub_type)
+        }
+    } else {
+        "void*".to_string()
+    }
+}
+
+/// Walks every cached Class/Struct/Enum/Function into an `SdkType`/`SdkFunction`, ready to be
+/// topologically sorted and rendered by one of the `FileBuilder` implementations below.
+fn collect_types(obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset) -> (Vec<SdkType>, HashMap<usize, SdkType>) {
+    let mut types: Vec<SdkType> = Vec::new();
+    let mut functions_by_owner: HashMap<usize, Vec<SdkFunction>> = HashMap::new();
+    let enums: HashMap<usize, SdkType> = HashMap::new();
+
+    // First pass: functions, grouped by their owning class/struct (obj.outer).
+    for entry in obj_mgr.cache_by_address.iter() {
+        let obj = entry.value();
+        if !obj.type_name.to_lowercase().contains("function") {
+            continue;
+        }
+
+        let func_address = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.funct)).unwrap_or(0);
+        let _ = func_address; // not needed for the header signature itself
+
+        let mut return_type = "void".to_string();
+        let mut params = Vec::new();
+
+        let mut param_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.funct_para)).unwrap_or(0);
+        let mut safety = 0;
+        while param_addr > 0x10000 && safety < 200 {
+            safety += 1;
+
+            let param_name_id = process.memory.try_read::<i32>(param_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+            let param_name = name_pool.get_name(process, param_name_id as u32).unwrap_or_default();
+
+            let type_ptr = process.memory.try_read_pointer(param_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+            let type_id = process.memory.try_read::<i32>(type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+            let param_type = name_pool.get_name(process, type_id as u32).unwrap_or_default();
+
+            let prop_0 = process.memory.try_read_pointer(param_addr.wrapping_add(offsets.property)).unwrap_or(0);
+            let mut sub_type = String::new();
+            if prop_0 > 0x10000 {
+                if let Some(sub_obj) = obj_mgr.cache_by_address.get(&prop_0) {
+                    sub_type = sub_obj.name.clone();
+                }
+            }
+
+            let cpp_type = to_cpp_type(&param_type, &sub_type, true);
+
+            if param_name == "ReturnValue" {
+                return_type = cpp_type;
+            } else if !param_name.is_empty() && !param_type.is_empty() {
+                params.push((cpp_type, param_name));
+            }
+
+            param_addr = process.memory.try_read_pointer(param_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+        }
+
+        if obj.outer > 0x10000 {
+            functions_by_owner.entry(obj.outer).or_default().push(SdkFunction { name: obj.name.clone(), return_type, params });
+        }
+    }
+
+    // Second pass: classes/structs/enums.
+    for entry in obj_mgr.cache_by_address.iter() {
+        let obj = entry.value();
+        let type_lower = obj.type_name.to_lowercase();
+        let package = extract_package_name(&obj.full_name);
+
+        if type_lower.contains("class") && !type_lower.contains("function") || type_lower.contains("struct") && !type_lower.contains("function") {
+            let kind = if type_lower.contains("class") { SdkKind::Class } else { SdkKind::Struct };
+            let super_address = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.super_struct)).unwrap_or(0);
+            let size = process.memory.try_read::<i32>(obj.address.wrapping_add(offsets.prop_size)).unwrap_or(0).max(0) as usize;
+
+            let mut properties = Vec::new();
+            let mut child_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.member)).unwrap_or(0);
+            let mut safety = 0;
+            while child_addr > 0x10000 && safety < 2000 {
+                safety += 1;
+
+                let child_name_id = process.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+                let child_name = name_pool.get_name(process, child_name_id as u32).unwrap_or_default();
+
+                let type_ptr = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+                let type_id = process.memory.try_read::<i32>(type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+                let child_type = name_pool.get_name(process, type_id as u32).unwrap_or_default();
+
+                let child_offset = process.memory.try_read::<i32>(child_addr.wrapping_add(offsets.offset)).unwrap_or(0).max(0) as usize;
+                let member_size = process.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_size)).unwrap_or(0).max(0) as usize;
+
+                let prop_0 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property)).unwrap_or(0);
+                let prop_8 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property + 8)).unwrap_or(0);
+                let type_obj = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.type_object)).unwrap_or(0);
+                let bit_mask = process.memory.try_read::<u8>(child_addr.wrapping_add(offsets.bit_mask)).unwrap_or(0);
+
+                let mut sub_type = String::new();
+                let mut sub_type_address = 0usize;
+                let mut sub_is_class = false;
+                for &addr in &[prop_8, prop_0, type_obj] {
+                    if addr > 0x10000 {
+                        if let Some(sub_obj) = obj_mgr.cache_by_address.get(&addr) {
+                            sub_type = sub_obj.name.clone();
+                            sub_type_address = sub_obj.address;
+                            sub_is_class = sub_obj.type_name.to_lowercase().contains("class");
+                            break;
+                        }
+                    }
+                }
+
+                if !child_name.is_empty() && !child_type.is_empty() {
+                    let type_lower_child = child_type.to_lowercase();
+                    let dependency = if type_lower_child.contains("structproperty") && sub_type_address != 0 { Some(sub_type_address) } else { None };
+                    let cpp_type = to_cpp_type(&child_type, &sub_type, sub_is_class);
+                    let bit_mask = if type_lower_child.contains("boolproperty") { bit_mask } else { 0 };
+                    properties.push(SdkProperty { name: child_name, ue_type: child_type, cpp_type, sub_type, offset: child_offset, size: if member_size > 0 { member_size } else { 1 }, bit_mask, dependency });
+                }
+
+                child_addr = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+            }
+            properties.sort_by_key(|p| p.offset);
+
+            let functions = functions_by_owner.remove(&obj.address).unwrap_or_default();
+
+            types.push(SdkType { address: obj.address, name: obj.name.clone(), package, super_address, kind, size, properties, enum_values: vec![], enum_underlying: String::new(), functions });
+        } else if type_lower.starts_with("enum") || type_lower == "userenum" {
+            let enum_type_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.enum_type)).unwrap_or(0);
+            let mut enum_underlying = "uint8".to_string();
+            if enum_type_addr > 0x10000 {
+                let type_name_id = process.memory.try_read::<i32>(enum_type_addr.wrapping_add(offsets.fname_index)).unwrap_or(0);
+                if let Ok(name) = name_pool.get_name(process, type_name_id as u32) {
+                    if !name.is_empty() {
+                        enum_underlying = name;
+                    }
+                }
+            }
+
+            let list_ptr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.enum_list)).unwrap_or(0);
+            let list_count = process.memory.try_read::<i32>(obj.address.wrapping_add(offsets.enum_size)).unwrap_or(0);
+            let mut enum_values = Vec::new();
+            if list_ptr > 0x10000 && list_count > 0 && list_count < 10000 {
+                for i in 0..list_count as usize {
+                    let entry_addr = list_ptr.wrapping_add(i * offsets.enum_prop_mul);
+                    let name_id = process.memory.try_read::<i32>(entry_addr.wrapping_add(offsets.enum_prop_name)).unwrap_or(0);
+                    let enum_name = name_pool.get_name(process, name_id as u32).unwrap_or_default();
+                    let enum_value = process.memory.try_read::<i64>(entry_addr.wrapping_add(offsets.enum_prop_index)).unwrap_or(0);
+                    if !enum_name.is_empty() {
+                        enum_values.push((enum_name, enum_value));
+                    }
+                }
+            }
+
+            types.push(SdkType { address: obj.address, name: obj.name.clone(), package, super_address: 0, kind: SdkKind::Enum, size: 0, properties: vec![], enum_values, enum_underlying, functions: vec![] });
+        }
+    }
+
+    (types, enums)
+}
+
+/// Topologically sorts `types` so a type's `SuperStruct` parent and any `StructProperty`
+/// sub-types it embeds by value are declared before it. Cycles (which shouldn't occur in a
+/// real UE type graph, but a mis-detected offset profile could manufacture one) are broken by
+/// simply skipping the back-edge rather than erroring out.
+fn topo_sort(types: Vec<SdkType>) -> Vec<SdkType> {
+    let by_address: HashMap<usize, usize> = types.iter().enumerate().map(|(i, t)| (t.address, i)).collect();
+    let mut visited = vec![false; types.len()];
+    let mut in_progress = vec![false; types.len()];
+    let mut order = Vec::with_capacity(types.len());
+
+    fn visit(idx: usize, types: &[SdkType], by_address: &HashMap<usize, usize>, visited: &mut Vec<bool>, in_progress: &mut Vec<bool>, order: &mut Vec<usize>) {
+        if visited[idx] || in_progress[idx] {
+            return;
+        }
+        in_progress[idx] = true;
+
+        let t = &types[idx];
+        let mut deps: Vec<usize> = Vec::new();
+        if t.super_address != 0 {
+            deps.push(t.super_address);
+        }
+        for p in &t.properties {
+            if let Some(dep) = p.dependency {
+                deps.push(dep);
+            }
+        }
+
+        for dep in deps {
+            if let Some(&dep_idx) = by_address.get(&dep) {
+                if dep_idx != idx {
+                    visit(dep_idx, types, by_address, visited, in_progress, order);
+                }
+            }
+        }
+
+        in_progress[idx] = false;
+        visited[idx] = true;
+        order.push(idx);
+    }
+
+    for idx in 0..types.len() {
+        visit(idx, &types, &by_address, &mut visited, &mut in_progress, &mut order);
+    }
+
+    let mut types: Vec<Option<SdkType>> = types.into_iter().map(Some).collect();
+    order.into_iter().map(|idx| types[idx].take().unwrap()).collect()
+}
+
+/// Groups already topo-sorted types by package, deduplicating so a type that somehow resolves
+/// to the same address twice (shouldn't happen given `cache_by_address`'s own uniqueness, but
+/// cheap to guard against) is only ever emitted into one package's output.
+fn group_by_package(sorted: &[SdkType]) -> (Vec<String>, HashMap<String, Vec<&SdkType>>) {
+    let mut per_package: HashMap<String, Vec<&SdkType>> = HashMap::new();
+    let mut package_order: Vec<String> = Vec::new();
+    let mut seen_packages: HashSet<String> = HashSet::new();
+    let mut emitted: HashSet<usize> = HashSet::new();
+
+    for t in sorted {
+        if t.package.is_empty() || !emitted.insert(t.address) {
+            continue;
+        }
+        if seen_packages.insert(t.package.clone()) {
+            package_order.push(t.package.clone());
+        }
+        per_package.entry(t.package.clone()).or_default().push(t);
+    }
+
+    (package_order, per_package)
+}
+
+/// Dispatches a topo-sorted, package-grouped type graph into one generated file per package plus
+/// whatever aggregate file the format needs — the same builder-enum-dispatch shape
+/// `commands::QueryMatcher` already uses to pick a text-matching strategy, just picking an output
+/// format instead. `CppBuilder`/`RustBuilder`/`JsonBuilder` below are the three implementations.
+trait FileBuilder {
+    fn file_extension(&self) -> &'static str;
+    fn render_package(&self, package: &str, types: &[&SdkType], by_address: &HashMap<usize, &SdkType>) -> String;
+    /// The file tying every per-package file together (C++'s `SDK.h` master include, Rust's
+    /// `mod.rs`). `None` for formats that don't need one (JSON).
+    fn render_master(&self, file_names: &[String]) -> Option<GeneratedHeader>;
+}
+
+fn build_files(builder: &dyn FileBuilder, sorted: &[SdkType], by_address: &HashMap<usize, &SdkType>) -> Vec<GeneratedHeader> {
+    let (package_order, per_package) = group_by_package(sorted);
+
+    let mut files = Vec::new();
+    let mut file_names = Vec::new();
+    for package in &package_order {
+        let entries = &per_package[package];
+        let file_name = format!("{}.{}", sanitize(package), builder.file_extension());
+        let content = builder.render_package(package, entries, by_address);
+        file_names.push(file_name.clone());
+        files.push(GeneratedHeader { package_name: package.clone(), file_name, content });
+    }
+
+    if let Some(master) = builder.render_master(&file_names) {
+        files.push(master);
+    }
+
+    files
+}
+
+/// Reconstructs compilable-looking `#pragma pack`ed C++ structs, with explicit `PADDING(n)`
+/// filler between properties, so gaps left by un-reflected engine-internal fields show up as a
+/// fixed-size blob instead of silently shifting every later member's offset.
+struct CppBuilder;
+
+const CPP_PADDING_MACRO: &str = "#ifndef UEDP_PADDING_MACRO\n#define UEDP_PADDING_MACRO\n#define PADDING_IMPL(SIZE, LINE) unsigned char Padding_##LINE[SIZE]\n#define PADDING(SIZE) PADDING_IMPL(SIZE, __LINE__)\n#endif\n\n";
+
+impl CppBuilder {
+    fn render_type(&self, t: &SdkType, by_address: &HashMap<usize, &SdkType>) -> String {
+        match t.kind {
+            SdkKind::Enum => {
+                let mut out = format!("enum class {} : {}\n{{\n", t.name, t.enum_underlying);
+                for (name, value) in &t.enum_values {
+                    out.push_str(&format!("    {} = {},\n", name, value));
+                }
+                out.push_str("};\n\n");
+                out
+            }
+            SdkKind::Class | SdkKind::Struct => {
+                let keyword = match t.kind {
+                    SdkKind::Class => "class",
+                    _ => "struct",
+                };
+                let super_name = by_address.get(&t.super_address).map(|s| s.name.clone());
+                let mut out = match super_name {
+                    Some(name) => format!("// Size: 0x{:X}\n#pragma pack(push, 0x1)\n{} {} : public {}\n{{\npublic:\n", t.size, keyword, t.name, name),
+                    None => format!("// Size: 0x{:X}\n#pragma pack(push, 0x1)\n{} {}\n{{\npublic:\n", t.size, keyword, t.name),
+                };
+
+                let mut cursor = 0usize;
+                for p in &t.properties {
+                    if p.offset > cursor {
+                        out.push_str(&format!("    PADDING(0x{:X});\n", p.offset - cursor));
+                    }
+                    if p.bit_mask != 0 {
+                        // BoolProperty: multiple bools commonly share one byte, distinguished only
+                        // by `bit_mask` — render as a named single-bit field instead of a real
+                        // `bool`, since several of these can land at the same `offset`.
+                        out.push_str(&format!("    uint8 {} : 1; // 0x{:X} (bit mask 0x{:02X})\n", p.name, p.offset, p.bit_mask));
+                    } else {
+                        out.push_str(&format!("    {} {}; // 0x{:X}\n", p.cpp_type, p.name, p.offset));
+                    }
+                    cursor = p.offset + p.size;
+                }
+                if t.size > cursor {
+                    out.push_str(&format!("    PADDING(0x{:X});\n", t.size - cursor));
+                }
+
+                if !t.functions.is_empty() {
+                    out.push('\n');
+                    for f in &t.functions {
+                        let params = f.params.iter().map(|(ty, name)| format!("{} {}", ty, name)).collect::<Vec<_>>().join(", ");
+                        out.push_str(&format!("    {} {}({});\n", f.return_type, f.name, params));
+                    }
+                }
+
+                out.push_str("};\n#pragma pack(pop)\n\n");
+                out
+            }
+        }
+    }
+}
+
+impl FileBuilder for CppBuilder {
+    fn file_extension(&self) -> &'static str {
+        "h"
+    }
+
+    fn render_package(&self, package: &str, types: &[&SdkType], by_address: &HashMap<usize, &SdkType>) -> String {
+        let mut content = String::new();
+        content.push_str("#pragma once\n\n");
+        content.push_str(CPP_PADDING_MACRO);
+        content.push_str(&format!("// Generated SDK header for package {}\n\n", package));
+        for t in types {
+            content.push_str(&self.render_type(t, by_address));
+        }
+        content
+    }
+
+    fn render_master(&self, file_names: &[String]) -> Option<GeneratedHeader> {
+        let mut content = String::from("#pragma once\n\n");
+        for file_name in file_names {
+            content.push_str(&format!("#include \"{}\"\n", file_name));
+        }
+        Some(GeneratedHeader { package_name: "SDK".to_string(), file_name: "SDK.h".to_string(), content })
+    }
+}
+
+/// Maps a property the same way `to_cpp_type` does, but onto a `#[repr(C)]`-safe Rust type.
+/// Fields whose real layout isn't a fixed-width scalar the reflection data already resolved
+/// (`TArray`/`TMap`/`TSet`/`FString`/`FName`/`FText`) fall back to a `[u8; size]` blob using the
+/// already-parsed `member_size`, since `Vec<T>` isn't layout-compatible with UE's own containers.
+fn to_rust_type(property_type: &str, sub_type: &str, size: usize) -> String {
+    let t = property_type.to_lowercase();
+    if t.contains("boolproperty") {
+        "bool".to_string()
+    } else if t.contains("byteproperty") {
+        "u8".to_string()
+    } else if t.contains("int64property") {
+        "i64".to_string()
+    } else if t.contains("uint64property") {
+        "u64".to_string()
+    } else if t.contains("int16property") {
+        "i16".to_string()
+    } else if t.contains("uint16property") {
+        "u16".to_string()
+    } else if t.contains("uint32property") {
+        "u32".to_string()
+    } else if t.contains("intproperty") || t.contains("int32property") {
+        "i32".to_string()
+    } else if t.contains("doubleproperty") {
+        "f64".to_string()
+    } else if t.contains("floatproperty") {
+        "f32".to_string()
+    } else if t.contains("enumproperty") {
+        if sub_type.is_empty() {
+            "u8".to_string()
+        } else {
+            sub_type.to_string()
+        }
+    } else if t.contains("structproperty") && !sub_type.is_empty() {
+        sub_type.to_string()
+    } else if t.contains("objectproperty") || t.contains("classproperty") || t.contains("softobjectproperty") || t.contains("weakobjectproperty") || t.contains("softclassproperty") || t.contains("interfaceproperty") {
+        "usize".to_string() // raw pointer, stored as an address rather than a typed reference
+    } else {
+        format!("[u8; 0x{:X}]", size.max(1))
+    }
+}
+
+/// Maps an `SdkType::enum_underlying` name (whatever `FNamePool` resolved the enum's storage
+/// type to, e.g. `"uint8"`/`"Int64Property"`, or the `"uint8"` fallback) onto a Rust repr type.
+fn enum_underlying_to_rust(underlying: &str) -> &'static str {
+    let t = underlying.to_lowercase();
+    if t.contains("int64") {
+        if t.contains("uint64") {
+            "u64"
+        } else {
+            "i64"
+        }
+    } else if t.contains("uint32") {
+        "u32"
+    } else if t.contains("int32") || t == "int" {
+        "i32"
+    } else if t.contains("uint16") {
+        "u16"
+    } else if t.contains("int16") {
+        "i16"
+    } else {
+        "u8"
+    }
+}
+
+/// Rust mirror of `CppBuilder`: one `#[repr(C)]` struct per class/struct with explicit
+/// `_pad_0xN` byte-array fields standing in for `PADDING(n)`, and a `#[repr(<underlying>)]` enum
+/// per `UEnum`. BoolProperty bitfields aren't representable as real Rust bitfields without an
+/// extra crate, so (like the padding gaps) they're emitted as a plain `u8` with the bit mask in
+/// a comment.
+struct RustBuilder;
+
+impl RustBuilder {
+    fn render_type(&self, t: &SdkType, by_address: &HashMap<usize, &SdkType>) -> String {
+        match t.kind {
+            SdkKind::Enum => {
+                let repr = enum_underlying_to_rust(&t.enum_underlying);
+                let mut out = format!("#[repr({})]\npub enum {} {{\n", repr, t.name);
+                for (name, value) in &t.enum_values {
+                    out.push_str(&format!("    {} = {},\n", name, value));
+                }
+                out.push_str("}\n\n");
+                out
+            }
+            SdkKind::Class | SdkKind::Struct => {
+                let mut out = format!("// Size: 0x{:X}\n#[repr(C)]\npub struct {} {{\n", t.size, t.name);
+
+                let mut cursor = 0usize;
+                if let Some(super_type) = by_address.get(&t.super_address) {
+                    out.push_str(&format!("    pub base: {}, // 0x0\n", super_type.name));
+                    cursor = super_type.size;
+                }
+
+                for p in &t.properties {
+                    if p.offset > cursor {
+                        out.push_str(&format!("    pub _pad_0x{:X}: [u8; 0x{:X}],\n", cursor, p.offset - cursor));
+                    }
+                    if p.bit_mask != 0 {
+                        out.push_str(&format!("    pub {}: u8, // 0x{:X} (bit mask 0x{:02X})\n", p.name, p.offset, p.bit_mask));
+                    } else {
+                        out.push_str(&format!("    pub {}: {}, // 0x{:X}\n", p.name, to_rust_type(&p.ue_type, &p.sub_type, p.size), p.offset));
+                    }
+                    cursor = p.offset + p.size;
+                }
+                if t.size > cursor {
+                    out.push_str(&format!("    pub _pad_0x{:X}: [u8; 0x{:X}],\n", cursor, t.size - cursor));
+                }
+
+                out.push_str("}\n\n");
+                out
+            }
+        }
+    }
+}
+
+impl FileBuilder for RustBuilder {
+    fn file_extension(&self) -> &'static str {
+        "rs"
+    }
+
+    fn render_package(&self, package: &str, types: &[&SdkType], by_address: &HashMap<usize, &SdkType>) -> String {
+        let mut content = format!("#![allow(non_snake_case, non_camel_case_types, dead_code)]\n// Generated SDK module for package {}\n\n", package);
+        for t in types {
+            content.push_str(&self.render_type(t, by_address));
+        }
+        content
+    }
+
+    fn render_master(&self, file_names: &[String]) -> Option<GeneratedHeader> {
+        let mut content = String::new();
+        for file_name in file_names {
+            let module = file_name.trim_end_matches(".rs");
+            content.push_str(&format!("pub mod {};\n", module));
+        }
+        Some(GeneratedHeader { package_name: "SDK".to_string(), file_name: "mod.rs".to_string(), content })
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct JsonMember {
+    pub name: String,
+    pub type_name: String,
+    pub offset: usize,
+    pub sub_type: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct JsonObject {
+    pub name: String,
+    pub kind: String,
+    pub super_name: String,
+    pub size: usize,
+    pub members: Vec<JsonMember>,
+}
+
+#[derive(serde::Serialize)]
+pub struct JsonPackage {
+    pub package: String,
+    pub objects: Vec<JsonObject>,
+}
+
+/// Same package -> object -> member model as the C++/Rust output, for tooling that wants to
+/// consume the parsed type graph without parsing generated source.
+struct JsonBuilder;
+
+impl FileBuilder for JsonBuilder {
+    fn file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn render_package(&self, package: &str, types: &[&SdkType], by_address: &HashMap<usize, &SdkType>) -> String {
+        let objects = types
+            .iter()
+            .map(|t| {
+                let kind = match t.kind {
+                    SdkKind::Class => "Class",
+                    SdkKind::Struct => "Struct",
+                    SdkKind::Enum => "Enum",
+                };
+                let super_name = by_address.get(&t.super_address).map(|s| s.name.clone()).unwrap_or_default();
+
+                let members = if matches!(t.kind, SdkKind::Enum) {
+                    t.enum_values.iter().map(|(name, value)| JsonMember { name: name.clone(), type_name: t.enum_underlying.clone(), offset: *value as usize, sub_type: String::new() }).collect()
+                } else {
+                    t.properties.iter().map(|p| JsonMember { name: p.name.clone(), type_name: p.ue_type.clone(), offset: p.offset, sub_type: p.sub_type.clone() }).collect()
+                };
+
+                JsonObject { name: t.name.clone(), kind: kind.to_string(), super_name, size: t.size, members }
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&JsonPackage { package: package.to_string(), objects }).unwrap_or_default()
+    }
+
+    fn render_master(&self, _file_names: &[String]) -> Option<GeneratedHeader> {
+        None
+    }
+}
+
+fn builder_for(format: SdkExportFormat) -> Box<dyn FileBuilder> {
+    match format {
+        SdkExportFormat::Cpp => Box::new(CppBuilder),
+        SdkExportFormat::Rust => Box::new(RustBuilder),
+        SdkExportFormat::Json => Box::new(JsonBuilder),
+    }
+}
+
+/// Reconstructs compilable-looking C++ headers (one per package, plus a master include) from
+/// the already-parsed object graph — the same property/inheritance/enum data `get_object_details`
+/// shows in the UI, but rendered as a real SDK instead of a read-only tree.
+pub fn generate_sdk(obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset) -> Vec<GeneratedHeader> {
+    let (types, _) = collect_types(obj_mgr, process, name_pool, offsets);
+    let sorted = topo_sort(types);
+    let by_address: HashMap<usize, &SdkType> = sorted.iter().map(|t| (t.address, t)).collect();
+
+    build_files(&CppBuilder, &sorted, &by_address)
+}
+
+#[derive(Clone, serde::Serialize)]
+struct SdkExportProgressPayload {
+    packages_done: usize,
+    packages_total: usize,
+}
+
+/// Writes a full SDK export to `out_dir` in the requested format (C++, Rust, or JSON), one file
+/// per package plus whatever master file the format needs, streaming progress via the
+/// `sdk-export-progress` event. Touches the whole object cache, so callers (see
+/// `commands::export_sdk`) are expected to run this inside `spawn_blocking` rather than on the
+/// command's own async task.
+pub fn export_sdk_to_disk(obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset, format: SdkExportFormat, out_dir: &Path, app_handle: &tauri::AppHandle) -> Result<usize, String> {
+    use tauri::Emitter;
+
+    std::fs::create_dir_all(out_dir).map_err(|e| format!("Failed to create output directory {:?}: {}", out_dir, e))?;
+
+    let (types, _) = collect_types(obj_mgr, process, name_pool, offsets);
+    let sorted = topo_sort(types);
+    let by_address: HashMap<usize, &SdkType> = sorted.iter().map(|t| (t.address, t)).collect();
+
+    let files = build_files(builder_for(format).as_ref(), &sorted, &by_address);
+    let total = files.len();
+    for (i, file) in files.iter().enumerate() {
+        let path = out_dir.join(&file.file_name);
+        std::fs::write(&path, &file.content).map_err(|e| format!("Failed to write {:?}: {}", path, e))?;
+        app_handle.emit("sdk-export-progress", SdkExportProgressPayload { packages_done: i + 1, packages_total: total }).ok();
+    }
+
+    Ok(total)
+}
@@ -0,0 +1,194 @@
+use crate::backend::unreal::offsets::UEOffset;
+
+/// Minimal DWARF constants this emitter needs — just enough tags/attributes/forms to describe a
+/// handful of flat structs and a few absolute-address globals, not a general-purpose DWARF writer.
+mod dw {
+    pub const TAG_COMPILE_UNIT: u64 = 0x11;
+    pub const TAG_STRUCTURE_TYPE: u64 = 0x13;
+    pub const TAG_MEMBER: u64 = 0x0D;
+    pub const TAG_VARIABLE: u64 = 0x34;
+
+    pub const AT_NAME: u64 = 0x03;
+    pub const AT_BYTE_SIZE: u64 = 0x0B;
+    pub const AT_DATA_MEMBER_LOCATION: u64 = 0x38;
+    pub const AT_PRODUCER: u64 = 0x25;
+    pub const AT_LOCATION: u64 = 0x02;
+
+    pub const FORM_ADDR: u64 = 0x01;
+    pub const FORM_BLOCK1: u64 = 0x0A;
+    pub const FORM_STRING: u64 = 0x08;
+    pub const FORM_UDATA: u64 = 0x0F;
+
+    pub const OP_ADDR: u8 = 0x03;
+}
+
+fn write_uleb128(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn write_cstr(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(s.as_bytes());
+    out.push(0);
+}
+
+/// Abbreviation codes shared by every compile unit this module emits, declared once in
+/// `.debug_abbrev` and referenced by code from every DIE in `.debug_info`.
+const ABBREV_COMPILE_UNIT: u64 = 1;
+const ABBREV_STRUCTURE_TYPE: u64 = 2;
+const ABBREV_MEMBER: u64 = 3;
+const ABBREV_VARIABLE: u64 = 4;
+
+fn build_debug_abbrev() -> Vec<u8> {
+    let mut out = Vec::new();
+
+    let declare = |out: &mut Vec<u8>, code: u64, tag: u64, has_children: bool, attrs: &[(u64, u64)]| {
+        write_uleb128(out, code);
+        write_uleb128(out, tag);
+        out.push(has_children as u8);
+        for &(at, form) in attrs {
+            write_uleb128(out, at);
+            write_uleb128(out, form);
+        }
+        write_uleb128(out, 0);
+        write_uleb128(out, 0);
+    };
+
+    declare(&mut out, ABBREV_COMPILE_UNIT, dw::TAG_COMPILE_UNIT, true, &[(dw::AT_NAME, dw::FORM_STRING), (dw::AT_PRODUCER, dw::FORM_STRING)]);
+    declare(&mut out, ABBREV_STRUCTURE_TYPE, dw::TAG_STRUCTURE_TYPE, true, &[(dw::AT_NAME, dw::FORM_STRING), (dw::AT_BYTE_SIZE, dw::FORM_UDATA)]);
+    declare(&mut out, ABBREV_MEMBER, dw::TAG_MEMBER, false, &[(dw::AT_NAME, dw::FORM_STRING), (dw::AT_DATA_MEMBER_LOCATION, dw::FORM_UDATA)]);
+    declare(&mut out, ABBREV_VARIABLE, dw::TAG_VARIABLE, false, &[(dw::AT_NAME, dw::FORM_STRING), (dw::AT_LOCATION, dw::FORM_BLOCK1)]);
+
+    write_uleb128(&mut out, 0); // end of abbreviation table
+    out
+}
+
+/// One resolved global pointer (`GUObjectArray`, `FNamePool`, `GWorld`, ...) to emit as a
+/// `DW_TAG_variable` with a `DW_OP_addr` location, and as a row in the plain label map.
+pub struct ResolvedGlobal {
+    pub name: &'static str,
+    pub address: usize,
+}
+
+/// One UE struct this emitter knows how to describe, built from `UEOffset` fields rather than the
+/// live object cache — `UObject`/`UStruct`/`FProperty` are the reflection primitives every other
+/// exporter in this crate already chases pointers through, so they're the ones worth handing a
+/// debugger concrete offsets for.
+struct UeType {
+    name: &'static str,
+    members: Vec<(&'static str, usize)>,
+}
+
+fn ue_types(offsets: &UEOffset) -> Vec<UeType> {
+    vec![
+        UeType {
+            name: "UObject",
+            members: vec![("VTable", 0), ("InternalIndex", offsets.id), ("ClassPrivate", offsets.class), ("NamePrivate", offsets.fname_index), ("OuterPrivate", offsets.outer)],
+        },
+        UeType {
+            name: "UStruct",
+            members: vec![("SuperStruct", offsets.super_struct), ("Children", offsets.member), ("PropertiesSize", offsets.prop_size)],
+        },
+        UeType {
+            name: "FProperty",
+            members: vec![("ClassPrivate", offsets.member_type_offset), ("ElementSize", offsets.member_size), ("Offset_Internal", offsets.offset), ("Property_0", offsets.property), ("Next", offsets.next_member)],
+        },
+    ]
+}
+
+/// Writes a `DW_TAG_compile_unit` DIE (header + body), patching the leading `unit_length` once
+/// the body's byte length is known.
+fn write_compile_unit(out: &mut Vec<u8>, body: impl FnOnce(&mut Vec<u8>)) {
+    let length_pos = out.len();
+    out.extend_from_slice(&0u32.to_le_bytes()); // placeholder, patched below
+
+    let start = out.len();
+    out.extend_from_slice(&4u16.to_le_bytes()); // DWARF version 4
+    out.extend_from_slice(&0u32.to_le_bytes()); // debug_abbrev_offset: the one shared table at offset 0
+    out.push(8); // address_size: 64-bit targets only
+
+    body(out);
+
+    let unit_length = (out.len() - start) as u32;
+    out[length_pos..length_pos + 4].copy_from_slice(&unit_length.to_le_bytes());
+}
+
+/// Builds the resolved globals and `UEOffset`-derived struct layouts into artifacts a debugger
+/// or disassembler can load directly, so `GUObjectArray`/`FNamePool`/`GWorld` and named struct
+/// members show up as symbols instead of raw hex.
+pub struct SymbolExporter;
+
+impl SymbolExporter {
+    /// Emits a minimal `.debug_abbrev`/`.debug_info` pair: one compile unit per `UeType`
+    /// (`UObject`, `UStruct`, `FProperty`), each holding a `DW_TAG_structure_type` whose members
+    /// carry `DW_AT_data_member_location` straight from the active `UEOffset`, plus a final
+    /// compile unit holding every resolved global as a `DW_TAG_variable` with a `DW_OP_addr`
+    /// location. Good enough for a DWARF-aware disassembler to label structs/globals; not a
+    /// general-purpose DWARF writer (no line tables, no `.debug_str`, inline `DW_FORM_string` only).
+    pub fn export_dwarf(offsets: &UEOffset, globals: &[ResolvedGlobal]) -> (Vec<u8>, Vec<u8>) {
+        let debug_abbrev = build_debug_abbrev();
+        let mut debug_info = Vec::new();
+
+        for ty in ue_types(offsets) {
+            write_compile_unit(&mut debug_info, |out| {
+                write_uleb128(out, ABBREV_COMPILE_UNIT);
+                write_cstr(out, ty.name);
+                write_cstr(out, "UEDP_JH SymbolExporter");
+
+                write_uleb128(out, ABBREV_STRUCTURE_TYPE);
+                write_cstr(out, ty.name);
+                let byte_size = ty.members.iter().map(|(_, off)| *off).max().unwrap_or(0) + 8;
+                write_uleb128(out, byte_size as u64);
+
+                for (name, offset) in &ty.members {
+                    write_uleb128(out, ABBREV_MEMBER);
+                    write_cstr(out, name);
+                    write_uleb128(out, *offset as u64);
+                }
+                write_uleb128(out, 0); // end structure_type children
+
+                write_uleb128(out, 0); // end compile_unit children
+            });
+        }
+
+        write_compile_unit(&mut debug_info, |out| {
+            write_uleb128(out, ABBREV_COMPILE_UNIT);
+            write_cstr(out, "Globals");
+            write_cstr(out, "UEDP_JH SymbolExporter");
+
+            for global in globals {
+                write_uleb128(out, ABBREV_VARIABLE);
+                write_cstr(out, global.name);
+
+                let mut location = Vec::with_capacity(9);
+                location.push(dw::OP_ADDR);
+                location.extend_from_slice(&(global.address as u64).to_le_bytes());
+                out.push(location.len() as u8);
+                out.extend_from_slice(&location);
+            }
+            write_uleb128(out, 0); // end compile_unit children
+        });
+
+        (debug_abbrev, debug_info)
+    }
+
+    /// Emits a plain `address label` map — the format x64dbg's "Add label" import and Cheat
+    /// Engine's address list both accept without extra tooling — so a resolved global is
+    /// recognizable at a glance without pulling in a DWARF-aware debugger.
+    pub fn export_label_map(globals: &[ResolvedGlobal]) -> String {
+        let mut out = String::from("; UEDP_JH resolved globals — x64dbg/CE label map\n");
+        for global in globals {
+            out.push_str(&format!("0x{:X} {}\n", global.address, global.name));
+        }
+        out
+    }
+}
@@ -0,0 +1,318 @@
+use crate::backend::os::process::Process;
+use crate::backend::unreal::name_pool::FNamePool;
+use crate::backend::unreal::object_array::ObjectManager;
+use crate::backend::unreal::offsets::UEOffset;
+use std::collections::HashMap;
+
+const USMAP_MAGIC: u16 = 0x30C4;
+const USMAP_VERSION: u8 = 0;
+const USMAP_COMPRESSION_NONE: u8 = 0;
+
+/// Usmap property type tags, matching the enumeration FModel/UAssetGUI/CUE4Parse expect.
+/// Anything we don't have a dedicated tag for (SoftObjectProperty, InterfaceProperty, ...)
+/// collapses to `Object`/`Unknown` the same way our other exporters degrade gracefully.
+#[repr(u8)]
+enum UsmapPropType {
+    Byte = 0,
+    Bool = 1,
+    Int = 2,
+    Float = 3,
+    Object = 4,
+    Name = 5,
+    Double = 6,
+    Array = 7,
+    Struct = 8,
+    Str = 9,
+    Text = 10,
+    Int16 = 11,
+    UInt16 = 12,
+    UInt32 = 13,
+    Int64 = 14,
+    UInt64 = 15,
+    Map = 16,
+    Set = 17,
+    Enum = 18,
+    Unknown = 19,
+}
+
+/// Interns names into a single table shared by the whole file (classes, structs, enums,
+/// enum values, and property names all reference it by index), matching how the binary
+/// format itself dedupes strings.
+#[derive(Default)]
+struct NameTable {
+    names: Vec<String>,
+    index: HashMap<String, u32>,
+}
+
+impl NameTable {
+    fn intern(&mut self, name: &str) -> u32 {
+        if let Some(&idx) = self.index.get(name) {
+            return idx;
+        }
+        let idx = self.names.len() as u32;
+        self.names.push(name.to_string());
+        self.index.insert(name.to_string(), idx);
+        idx
+    }
+}
+
+/// A property's type already resolved to name-table indices, so writing it out never needs
+/// to intern a new name (every name referenced anywhere in the file is known up front).
+struct ResolvedType {
+    type_name: String,
+    sub_name_index: Option<u32>,
+    /// Second sub-type for MapProperty's value (the key uses `sub_name_index`).
+    map_value_name_index: Option<u32>,
+}
+
+struct UsmapProperty {
+    name_index: u32,
+    array_dim: u8,
+    index: u16,
+    ty: ResolvedType,
+}
+
+struct UsmapStruct {
+    name_index: u32,
+    super_index: u32, // 0xFFFFFFFF if none
+    properties: Vec<UsmapProperty>,
+}
+
+struct UsmapEnum {
+    name_index: u32,
+    value_name_indices: Vec<u32>,
+}
+
+fn write_u16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+fn write_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+/// Encodes a property's type tag. Container/reference kinds (`ObjectProperty`, `StructProperty`,
+/// `EnumProperty`, `ArrayProperty`, `MapProperty`) carry their already-resolved inner name
+/// index(es) right after the tag, mirroring the usmap spec's nested `FPropertyData`. Inner
+/// element kinds we can't recover the exact property-type of (e.g. an array's element kind)
+/// fall back to `Object` when a name was resolved, or `Byte` otherwise.
+fn encode_property_type(out: &mut Vec<u8>, ty: &ResolvedType) {
+    let t = ty.type_name.to_lowercase();
+
+    if t.contains("boolproperty") {
+        out.push(UsmapPropType::Bool as u8);
+    } else if t.contains("byteproperty") {
+        out.push(UsmapPropType::Byte as u8);
+    } else if t.contains("int16property") {
+        out.push(UsmapPropType::Int16 as u8);
+    } else if t.contains("uint16property") {
+        out.push(UsmapPropType::UInt16 as u8);
+    } else if t.contains("uint32property") {
+        out.push(UsmapPropType::UInt32 as u8);
+    } else if t.contains("int64property") {
+        out.push(UsmapPropType::Int64 as u8);
+    } else if t.contains("uint64property") {
+        out.push(UsmapPropType::UInt64 as u8);
+    } else if t.contains("intproperty") {
+        out.push(UsmapPropType::Int as u8);
+    } else if t.contains("doubleproperty") {
+        out.push(UsmapPropType::Double as u8);
+    } else if t.contains("floatproperty") {
+        out.push(UsmapPropType::Float as u8);
+    } else if t.contains("nameproperty") {
+        out.push(UsmapPropType::Name as u8);
+    } else if t.contains("strproperty") {
+        out.push(UsmapPropType::Str as u8);
+    } else if t.contains("textproperty") {
+        out.push(UsmapPropType::Text as u8);
+    } else if t.contains("enumproperty") {
+        out.push(UsmapPropType::Enum as u8);
+        out.push(UsmapPropType::Byte as u8); // underlying storage type
+        write_u32(out, ty.sub_name_index.unwrap_or(0xFFFFFFFF));
+    } else if t.contains("structproperty") {
+        out.push(UsmapPropType::Struct as u8);
+        write_u32(out, ty.sub_name_index.unwrap_or(0xFFFFFFFF));
+    } else if t.contains("arrayproperty") {
+        out.push(UsmapPropType::Array as u8);
+        write_inner_tag(out, ty.sub_name_index);
+    } else if t.contains("setproperty") {
+        out.push(UsmapPropType::Set as u8);
+        write_inner_tag(out, ty.sub_name_index);
+    } else if t.contains("mapproperty") {
+        out.push(UsmapPropType::Map as u8);
+        write_inner_tag(out, ty.sub_name_index);
+        write_inner_tag(out, ty.map_value_name_index);
+    } else if t.contains("objectproperty") || t.contains("classproperty") || t.contains("softobjectproperty") || t.contains("weakobjectproperty") || t.contains("softclassproperty") || t.contains("interfaceproperty") {
+        out.push(UsmapPropType::Object as u8);
+        write_u32(out, ty.sub_name_index.unwrap_or(0xFFFFFFFF));
+    } else {
+        out.push(UsmapPropType::Unknown as u8);
+    }
+}
+
+fn write_inner_tag(out: &mut Vec<u8>, name_index: Option<u32>) {
+    match name_index {
+        Some(idx) => {
+            out.push(UsmapPropType::Object as u8);
+            write_u32(out, idx);
+        }
+        None => out.push(UsmapPropType::Byte as u8),
+    }
+}
+
+/// Walks every cached Class/Struct into `UsmapStruct`s and every Enum into `UsmapEnum`s,
+/// interning every referenced name into a shared `NameTable` as it goes.
+fn collect(obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset) -> (NameTable, Vec<UsmapEnum>, Vec<UsmapStruct>) {
+    let mut names = NameTable::default();
+    let mut enums = Vec::new();
+    let mut structs = Vec::new();
+
+    // super_struct addresses are resolved to an index into `structs` only once every struct has
+    // been collected, so stash the raw address alongside each entry for a second pass.
+    let mut super_addrs: Vec<usize> = Vec::new();
+    let mut struct_addr_to_index: HashMap<usize, usize> = HashMap::new();
+
+    for entry in obj_mgr.cache_by_address.iter() {
+        let obj = entry.value();
+        let type_lower = obj.type_name.to_lowercase();
+
+        if (type_lower.contains("class") || type_lower.contains("struct")) && !type_lower.contains("function") {
+            let name_index = names.intern(&obj.name);
+            let super_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.super_struct)).unwrap_or(0);
+
+            let mut properties = Vec::new();
+            let mut child_addr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.member)).unwrap_or(0);
+            let mut safety = 0;
+            let mut prop_index: u16 = 0;
+            while child_addr > 0x10000 && safety < 2000 {
+                safety += 1;
+
+                let child_name_id = process.memory.try_read::<i32>(child_addr.wrapping_add(offsets.member_fname_index)).unwrap_or(0);
+                let child_name = name_pool.get_name(process, child_name_id as u32).unwrap_or_default();
+
+                let type_ptr = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.member_type_offset)).unwrap_or(0);
+                let type_id = process.memory.try_read::<i32>(type_ptr.wrapping_add(offsets.member_type)).unwrap_or(0);
+                let child_type = name_pool.get_name(process, type_id as u32).unwrap_or_default();
+
+                let prop_0 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property)).unwrap_or(0);
+                let prop_8 = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.property + 8)).unwrap_or(0);
+                let type_obj = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.type_object)).unwrap_or(0);
+
+                let type_lower_child = child_type.to_lowercase();
+                let mut sub_name_index = None;
+                let mut map_value_name_index = None;
+
+                if type_lower_child.contains("mapproperty") {
+                    let resolved: Vec<u32> = [prop_0, prop_8]
+                        .iter()
+                        .filter_map(|&addr| if addr > 0x10000 { obj_mgr.cache_by_address.get(&addr).map(|o| names.intern(&o.name)) } else { None })
+                        .collect();
+                    sub_name_index = resolved.first().copied();
+                    map_value_name_index = resolved.get(1).copied();
+                } else {
+                    for &addr in &[prop_8, prop_0, type_obj] {
+                        if addr > 0x10000 {
+                            if let Some(sub_obj) = obj_mgr.cache_by_address.get(&addr) {
+                                sub_name_index = Some(names.intern(&sub_obj.name));
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                if !child_name.is_empty() && !child_type.is_empty() {
+                    properties.push(UsmapProperty {
+                        name_index: names.intern(&child_name),
+                        array_dim: 1,
+                        index: prop_index,
+                        ty: ResolvedType { type_name: child_type, sub_name_index, map_value_name_index },
+                    });
+                    prop_index += 1;
+                }
+
+                child_addr = process.memory.try_read_pointer(child_addr.wrapping_add(offsets.next_member)).unwrap_or(0);
+            }
+
+            struct_addr_to_index.insert(obj.address, structs.len());
+            super_addrs.push(super_addr);
+            structs.push(UsmapStruct { name_index, super_index: 0xFFFFFFFF, properties });
+        } else if type_lower.starts_with("enum") || type_lower == "userenum" {
+            let name_index = names.intern(&obj.name);
+
+            let list_ptr = process.memory.try_read_pointer(obj.address.wrapping_add(offsets.enum_list)).unwrap_or(0);
+            let list_count = process.memory.try_read::<i32>(obj.address.wrapping_add(offsets.enum_size)).unwrap_or(0);
+            let mut value_name_indices = Vec::new();
+            if list_ptr > 0x10000 && list_count > 0 && list_count < 10000 {
+                for i in 0..list_count as usize {
+                    let entry_addr = list_ptr.wrapping_add(i * offsets.enum_prop_mul);
+                    let name_id = process.memory.try_read::<i32>(entry_addr.wrapping_add(offsets.enum_prop_name)).unwrap_or(0);
+                    let enum_value_name = name_pool.get_name(process, name_id as u32).unwrap_or_default();
+                    if !enum_value_name.is_empty() {
+                        value_name_indices.push(names.intern(&enum_value_name));
+                    }
+                }
+            }
+
+            enums.push(UsmapEnum { name_index, value_name_indices });
+        }
+    }
+
+    for (i, &super_addr) in super_addrs.iter().enumerate() {
+        if super_addr > 0x10000 {
+            if let Some(&super_idx) = struct_addr_to_index.get(&super_addr) {
+                structs[i].super_index = super_idx as u32;
+            }
+        }
+    }
+
+    (names, enums, structs)
+}
+
+/// Serializes the already-parsed classes/structs/enums into the binary `.usmap` format.
+/// Container: `u16` magic, `u8` version, `u8` compression method, `u32` compressed size,
+/// `u32` decompressed size, then the uncompressed payload (we always write method `0` / none).
+pub fn export_usmap(obj_mgr: &ObjectManager, process: &Process, name_pool: &FNamePool, offsets: &UEOffset) -> Vec<u8> {
+    let (names, enums, structs) = collect(obj_mgr, process, name_pool, offsets);
+
+    let mut payload = Vec::new();
+
+    write_u32(&mut payload, names.names.len() as u32);
+    for name in &names.names {
+        let bytes = name.as_bytes();
+        let len = bytes.len().min(255) as u8;
+        payload.push(len);
+        payload.extend_from_slice(&bytes[..len as usize]);
+    }
+
+    write_u32(&mut payload, enums.len() as u32);
+    for e in &enums {
+        write_u32(&mut payload, e.name_index);
+        write_u32(&mut payload, e.value_name_indices.len() as u32);
+        for &v in &e.value_name_indices {
+            write_u32(&mut payload, v);
+        }
+    }
+
+    write_u32(&mut payload, structs.len() as u32);
+    for s in &structs {
+        write_u32(&mut payload, s.name_index);
+        write_u32(&mut payload, s.super_index);
+        write_u16(&mut payload, s.properties.len() as u16);
+        write_u16(&mut payload, s.properties.len() as u16); // serializable-property count: all of them
+        for p in &s.properties {
+            payload.push(p.array_dim);
+            write_u16(&mut payload, p.index);
+            write_u32(&mut payload, p.name_index);
+            encode_property_type(&mut payload, &p.ty);
+        }
+    }
+
+    let mut file = Vec::with_capacity(payload.len() + 11);
+    write_u16(&mut file, USMAP_MAGIC);
+    file.push(USMAP_VERSION);
+    file.push(USMAP_COMPRESSION_NONE);
+    write_u32(&mut file, payload.len() as u32);
+    write_u32(&mut file, payload.len() as u32);
+    file.extend_from_slice(&payload);
+
+    file
+}